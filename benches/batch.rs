@@ -0,0 +1,118 @@
+//! Vergleicht `predecessors_batch` bzw. `successors_batch` mit einzelnen `locate_or_pred`- bzw.
+//! `locate_or_succ`-Aufrufen für u40 und u48. Ausführen mit `cargo bench --bench batch`. Mit
+//! `cargo bench --bench batch -- u48` wird nur die angegebene Gruppe ausgeführt. Der u48-STree benötigt allein für die
+//! Root-Tabelle 2^32 * 8 Byte = 32 GiB RAM.
+use std::time::{Duration, Instant};
+
+use ma_titan::default::immutable::{Int, STree};
+use rand::distributions::{Distribution, Uniform};
+use uint::{u40, u48};
+
+/// Anzahl der Elemente im STree.
+const ELEMENTS: usize = 1 << 22;
+
+/// Anzahl der Anfragen je Durchlauf.
+const QUERIES: usize = 1 << 22;
+
+/// Anzahl der Durchläufe, von denen der schnellste ausgegeben wird.
+const RUNS: usize = 5;
+
+/// Führt `f` `RUNS`-mal aus und gibt die kürzeste Laufzeit zurück.
+fn measure<F: FnMut() -> usize>(mut f: F) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report(group: &str, name: &str, time: Duration) {
+    println!(
+        "{:<4} {:<20} {:>10.2} ms {:>8.2} ns/Anfrage",
+        group,
+        name,
+        time.as_secs_f64() * 1e3,
+        time.as_secs_f64() * 1e9 / QUERIES as f64
+    );
+}
+
+/// Baut einen STree aus `ELEMENTS` zufälligen Werten mit `bits` Bits und misst alle Varianten mit `QUERIES` zufälligen
+/// Anfragen.
+fn bench_group<T: Int>(group: &str, bits: u32) {
+    let between = Uniform::from(0u64..(1 << bits));
+    let mut rng = rand::thread_rng();
+    let data_structure: STree<T> = (0..ELEMENTS)
+        .map(|_| T::new(between.sample(&mut rng)))
+        .collect();
+    let queries: Vec<T> = (0..QUERIES)
+        .map(|_| T::new(between.sample(&mut rng)))
+        .collect();
+
+    report(
+        group,
+        "locate_or_pred",
+        measure(|| {
+            queries
+                .iter()
+                .map(|&x| data_structure.locate_or_pred(x))
+                .collect::<Vec<_>>()
+                .iter()
+                .flatten()
+                .count()
+        }),
+    );
+    report(
+        group,
+        "predecessors_batch",
+        measure(|| {
+            data_structure
+                .predecessors_batch(&queries)
+                .iter()
+                .flatten()
+                .count()
+        }),
+    );
+    report(
+        group,
+        "locate_or_succ",
+        measure(|| {
+            queries
+                .iter()
+                .map(|&x| data_structure.locate_or_succ(x))
+                .collect::<Vec<_>>()
+                .iter()
+                .flatten()
+                .count()
+        }),
+    );
+    report(
+        group,
+        "successors_batch",
+        measure(|| {
+            data_structure
+                .successors_batch(&queries)
+                .iter()
+                .flatten()
+                .count()
+        }),
+    );
+}
+
+fn main() {
+    // `cargo bench` übergibt zusätzlich `--bench`, das hier ignoriert wird.
+    let groups: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .collect();
+    let selected = |group: &str| groups.is_empty() || groups.iter().any(|g| g == group);
+
+    if selected("u40") {
+        bench_group::<u40>("u40", 40);
+    }
+    if selected("u48") {
+        bench_group::<u48>("u48", 48);
+    }
+}
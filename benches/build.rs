@@ -0,0 +1,56 @@
+//! Misst die Laufzeit und den maximalen Speicherbedarf (Peak-RSS) von `STree::new` für sortierte, zufällige
+//! u40-Werte. Ausführen mit `cargo bench --bench build`. Der Speicherbedarf wird nur unter Linux ermittelt.
+use std::time::{Duration, Instant};
+
+use ma_titan::default::immutable::STree;
+use rand::distributions::{Distribution, Uniform};
+use uint::u40;
+
+/// Anzahl der Durchläufe je Eingabegröße, von denen der schnellste ausgegeben wird.
+const RUNS: usize = 3;
+
+/// Setzt den Peak-RSS des Prozesses auf den aktuellen RSS zurück.
+fn reset_peak_rss() {
+    let _ = std::fs::write("/proc/self/clear_refs", "5");
+}
+
+/// Gibt den Peak-RSS des Prozesses in MiB zurück, falls er ermittelt werden kann.
+fn peak_rss() -> Option<f64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kib: f64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kib / 1024.0)
+}
+
+fn main() {
+    let between = Uniform::from(0u64..(1 << 40));
+    let mut rng = rand::thread_rng();
+
+    for &len in &[1 << 16, 1 << 20, 1 << 22, 1 << 24] {
+        let mut elements: Vec<u40> = (0..len)
+            .map(|_| u40::new(between.sample(&mut rng)))
+            .collect();
+        elements.sort();
+        elements.dedup();
+
+        let mut time = Duration::MAX;
+        let mut peak = 0.0_f64;
+        for _ in 0..RUNS {
+            let input = elements.clone().into_boxed_slice();
+            reset_peak_rss();
+            let base = peak_rss().unwrap_or(0.0);
+            let start = Instant::now();
+            let data_structure = STree::new(input);
+            time = time.min(start.elapsed());
+            peak = peak.max(peak_rss().unwrap_or(0.0) - base);
+            std::hint::black_box(&data_structure);
+        }
+
+        println!(
+            "{:>9} Elemente {:>10.2} ms {:>10.1} MiB Peak-RSS",
+            elements.len(),
+            time.as_secs_f64() * 1e3,
+            peak
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use crate::default::immutable::{Int, L3Ebene, LXKey, Level, LevelView, STree};
+use crate::internal::{self, Splittable};
+
+/// Anzahl der Anfragen, die in `predecessors_batch` und `successors_batch` gemeinsam die einzelnen Stufen durchlaufen.
+/// Sie sollte groß genug sein, damit die Prefetches einer Stufe abgeschlossen sind, bevor die nächste Stufe beginnt.
+const PIPELINE_WIDTH: usize = 32;
+
+impl<T: Int> STree<T> {
+    /// Führt `locate_or_pred` für alle Werte aus `elements` aus und gibt die Ergebnisse in derselben Reihenfolge zurück.
+    /// `elements` muss aufsteigend sortiert sein. Dadurch kann die Position der vorherigen Anfrage wiederverwendet werden:
+    /// Liegt ein Wert vor dem Nachfolger der letzten Antwort, bleibt die Antwort gleich. Fällt er in dieselbe Wurzel-Ebene
+    /// wie die vorherige Anfrage, wird direkt in deren L2-Ebene abgestiegen.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Aufsteigend sortierte Liste von Anfragen. Duplikate sind erlaubt. Die Sortierung wird nur in
+    ///   Debug-Builds geprüft. Ist `elements` nicht sortiert, sind die Ergebnisse in Release-Builds falsch.
+    pub fn predecessors_sorted(&self, elements: &[T]) -> Vec<Option<usize>> {
+        if self.is_empty() {
+            return vec![None; elements.len()];
+        }
+
+        let mut result = Vec::with_capacity(elements.len());
+
+        // Zuletzt besuchte Wurzel-Ebene. Sie wird nur gemerkt, wenn die Anfrage nicht vor deren Minimum lag.
+        let mut finger: Option<(usize, &Level<L3Ebene<T>, T>)> = None;
+        let mut last: Option<usize> = None;
+
+        for (n, &element) in elements.iter().enumerate() {
+            debug_assert!(n == 0 || elements[n - 1] <= element);
+
+            // Die Antworten sind monoton steigend, sodass nur geprüft werden muss, ob der nächste Index bereits erreicht ist.
+            if let Some(index) = last {
+                if index + 1 == self.len() || element < self.element_list[index + 1] {
+                    result.push(last);
+                    continue;
+                }
+            }
+
+            let (i, j, k) = Splittable::split_integer_down(&element);
+            last = match finger {
+                Some((finger_i, second_level)) if finger_i == i => {
+                    self.locate_or_pred_l2(second_level, element, j, k)
+                }
+                _ => {
+                    finger = None;
+                    match self.root_table[i].view() {
+                        LevelView::Inner(l) if self.element_list[l.minimum] <= element => {
+                            finger = Some((i, l));
+                            self.locate_or_pred_l2(l, element, j, k)
+                        }
+                        LevelView::Singleton(index) if self.element_list[index] <= element => {
+                            Some(index)
+                        }
+                        // `element` liegt vor dem Minimum seiner Wurzel-Ebene (Paper z.3).
+                        _ => self
+                            .root_top
+                            .get_prev_set_bit(i)
+                            .map(|x| self.root_table[x].maximum()),
+                    }
+                }
+            };
+            result.push(last);
+        }
+        result
+    }
+
+    /// Führt `locate_or_succ` für alle Werte aus `elements` aus und gibt die Ergebnisse in derselben Reihenfolge zurück.
+    /// `elements` muss aufsteigend sortiert sein. Wie bei `predecessors_sorted` wird die vorherige Antwort und die zuletzt
+    /// besuchte L2-Ebene wiederverwendet.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Aufsteigend sortierte Liste von Anfragen. Duplikate sind erlaubt. Die Sortierung wird nur in
+    ///   Debug-Builds geprüft. Ist `elements` nicht sortiert, sind die Ergebnisse in Release-Builds falsch.
+    pub fn successors_sorted(&self, elements: &[T]) -> Vec<Option<usize>> {
+        if self.is_empty() {
+            return vec![None; elements.len()];
+        }
+
+        let mut result = Vec::with_capacity(elements.len());
+
+        // Zuletzt besuchte Wurzel-Ebene.
+        let mut finger: Option<(usize, &Level<L3Ebene<T>, T>)> = None;
+        let mut last: Option<usize> = None;
+
+        for (n, &element) in elements.iter().enumerate() {
+            debug_assert!(n == 0 || elements[n - 1] <= element);
+
+            if let Some(index) = last {
+                if element <= self.element_list[index] {
+                    result.push(last);
+                    continue;
+                }
+            } else if n > 0 {
+                // Die vorherige Anfrage lag hinter dem Maximum, also auch alle folgenden.
+                result.push(None);
+                continue;
+            }
+
+            let (i, j, k) = Splittable::split_integer_down(&element);
+            last = match finger {
+                // Liegt `element` hinter dem Maximum der L2-Ebene, befindet sich der Nachfolger in einer anderen Wurzel-Ebene.
+                Some((finger_i, second_level))
+                    if finger_i == i && element <= self.element_list[second_level.maximum] =>
+                {
+                    self.locate_or_succ_l2(second_level, element, j, k)
+                }
+                _ => {
+                    finger = None;
+                    match self.root_table[i].view() {
+                        LevelView::Inner(l) if element <= self.element_list[l.maximum] => {
+                            finger = Some((i, l));
+                            self.locate_or_succ_l2(l, element, j, k)
+                        }
+                        LevelView::Singleton(index) if element <= self.element_list[index] => {
+                            Some(index)
+                        }
+                        // `element` liegt hinter dem Maximum seiner Wurzel-Ebene (Paper z.3).
+                        _ => self
+                            .root_top
+                            .get_next_set_bit(i)
+                            .map(|x| self.root_table[x].minimum()),
+                    }
+                }
+            };
+            result.push(last);
+        }
+        result
+    }
+
+    /// Führt `locate_or_pred` für alle Werte aus `elements` aus und gibt die Ergebnisse in derselben Reihenfolge zurück.
+    /// Im Gegensatz zu `predecessors_sorted` dürfen die Anfragen unsortiert sein. Sie werden in Gruppen von
+    /// `PIPELINE_WIDTH` Anfragen stufenweise abgearbeitet, wobei jede Stufe den Speicher der nächsten Stufe für alle
+    /// Anfragen der Gruppe vorab lädt (siehe `pipelined`).
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Beliebige Liste von Anfragen.
+    pub fn predecessors_batch(&self, elements: &[T]) -> Vec<Option<usize>> {
+        self.pipelined(elements, Self::locate_or_pred, Self::locate_or_pred_l3)
+    }
+
+    /// Führt `locate_or_succ` für alle Werte aus `elements` aus und gibt die Ergebnisse in derselben Reihenfolge zurück.
+    /// Die Anfragen werden wie in `predecessors_batch` stufenweise mit Prefetching abgearbeitet.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Beliebige Liste von Anfragen.
+    pub fn successors_batch(&self, elements: &[T]) -> Vec<Option<usize>> {
+        self.pipelined(elements, Self::locate_or_succ, Self::locate_or_succ_l3)
+    }
+
+    /// Arbeitet `elements` in Gruppen ab. Innerhalb einer Gruppe werden nacheinander die Einträge der `root_table`, die
+    /// L2-Ebenen, deren Minima und Maxima in der Elementliste sowie die L3-Ebenen vorab geladen. Die in einer Stufe
+    /// ermittelten Ebenen werden je Anfrage gemerkt, sodass die letzte Stufe mit `finish` direkt ab der bereits
+    /// bestimmten L3-Ebene weitersucht. Nur Anfragen, die nicht in den Bereich ihrer L2-Ebene fallen (oder deren
+    /// Wurzel-Eintrag leer bzw. ein einzelner Index ist), werden vollständig mit `locate` beantwortet.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Beliebige Liste von Anfragen.
+    /// * `locate` - `locate_or_pred` bzw. `locate_or_succ`.
+    /// * `finish` - `locate_or_pred_l3` bzw. `locate_or_succ_l3`.
+    fn pipelined<F, G>(&self, elements: &[T], locate: F, finish: G) -> Vec<Option<usize>>
+    where
+        F: Fn(&Self, T) -> Option<usize>,
+        G: Fn(&Self, &Level<L3Ebene<T>, T>, Option<&L3Ebene<T>>, T, LXKey, LXKey) -> Option<usize>,
+    {
+        if self.is_empty() {
+            return vec![None; elements.len()];
+        }
+
+        let mut result = Vec::with_capacity(elements.len());
+        let mut keys = [(0_usize, 0 as LXKey, 0 as LXKey); PIPELINE_WIDTH];
+        // In Stufe 3 ermittelte L2- und L3-Ebene je Anfrage
+        let mut levels = [None; PIPELINE_WIDTH];
+        for group in elements.chunks(PIPELINE_WIDTH) {
+            // Stufe 1: Einträge der Root-Tabelle
+            for (n, element) in group.iter().enumerate() {
+                keys[n] = Splittable::split_integer_down(element);
+                internal::prefetch(&self.root_table[keys[n].0]);
+            }
+
+            // Stufe 2: L2-Ebenen bzw. Indizes, auf die die Root-Tabelle zeigt
+            for &(i, _, _) in &keys[..group.len()] {
+                if !self.root_table[i].is_null() {
+                    internal::prefetch(self.root_table[i].address());
+                }
+            }
+
+            // Stufe 3: Minimum und Maximum der L2-Ebene in der Elementliste sowie die L3-Ebene
+            for (n, &(i, j, _)) in keys[..group.len()].iter().enumerate() {
+                levels[n] = match self.root_table[i].view() {
+                    LevelView::Inner(l) => {
+                        internal::prefetch(&self.element_list[l.minimum]);
+                        internal::prefetch(&self.element_list[l.maximum]);
+                        let third_level = l.try_get(j);
+                        if let Some(third_level) = third_level {
+                            internal::prefetch(third_level.address());
+                        }
+                        Some((l, third_level))
+                    }
+                    _ => None,
+                };
+            }
+
+            // Stufe 4: Auflösen der Anfragen
+            for (n, &element) in group.iter().enumerate() {
+                let (_, j, k) = keys[n];
+                result.push(match levels[n] {
+                    Some((l, third_level))
+                        if self.element_list[l.minimum] <= element
+                            && element <= self.element_list[l.maximum] =>
+                    {
+                        finish(self, l, third_level, element, j, k)
+                    }
+                    _ => locate(self, element),
+                });
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,148 @@
+use crate::default::immutable::{Int, L3Ebene, LXKey, Level, LevelView, STree};
+use crate::internal::Splittable;
+
+impl<T: Int> STree<T> {
+    /// Gibt einen Iterator über alle nicht leeren Wurzel-Ebenen in aufsteigender Reihenfolge ihrer Präfixe zurück.
+    /// Die belegten Präfixe werden dabei über `root_top` bestimmt, sodass leere Einträge der `root_table` nicht
+    /// besucht werden.
+    pub fn buckets(&self) -> Buckets<'_, T> {
+        let next = self
+            .element_list
+            .first()
+            .map(|element| Splittable::split_integer_down(element).0);
+        Buckets { tree: self, next }
+    }
+}
+
+/// Iterator über alle nicht leeren Wurzel-Ebenen eines STrees. Er wird von `STree::buckets` erzeugt.
+#[derive(Clone)]
+pub struct Buckets<'a, T> {
+    tree: &'a STree<T>,
+
+    /// Präfix der nächsten zurückzugebenden Wurzel-Ebene.
+    next: Option<usize>,
+}
+
+impl<'a, T: Int> Iterator for Buckets<'a, T> {
+    type Item = Bucket<'a, T>;
+
+    fn next(&mut self) -> Option<Bucket<'a, T>> {
+        let prefix = self.next?;
+        let pointer = &self.tree.root_table[prefix];
+        let elements = &self.tree.element_list[pointer.minimum()..=pointer.maximum()];
+        let level = match pointer.view() {
+            LevelView::Inner(l) => Some(l),
+            _ => None,
+        };
+
+        self.next = self.tree.root_top.get_next_set_bit(prefix);
+        Some(Bucket {
+            prefix,
+            elements,
+            level,
+        })
+    }
+}
+
+impl<'a, T: Int> std::iter::FusedIterator for Buckets<'a, T> {}
+
+/// Lesende Sicht auf eine nicht leere Wurzel-Ebene eines STrees, also auf alle gespeicherten Elemente mit demselben
+/// Präfix (erster Teil von `split_integer_down`).
+#[derive(Clone, Copy)]
+pub struct Bucket<'a, T> {
+    prefix: usize,
+
+    /// Alle Elemente dieser Wurzel-Ebene als zusammenhängender Teil der Elementliste.
+    elements: &'a [T],
+
+    /// L2-Ebene dieser Wurzel-Ebene. Sie ist None, falls nur ein einziges Element gespeichert ist.
+    level: Option<&'a Level<L3Ebene<T>, T>>,
+}
+
+impl<'a, T: Int> Bucket<'a, T> {
+    /// Gibt das Präfix, also den Index in der `root_table`, zurück.
+    #[inline]
+    pub fn prefix(&self) -> usize {
+        self.prefix
+    }
+
+    /// Gibt die Anzahl der Elemente mit diesem Präfix zurück. Sie ist immer größer als 0.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Gibt an, ob die Wurzel-Ebene leer ist. Da `STree::buckets` nur belegte Präfixe liefert, ist das nie der Fall.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Gibt das kleinste Element mit diesem Präfix zurück.
+    #[inline]
+    pub fn minimum(&self) -> T {
+        self.elements[0]
+    }
+
+    /// Gibt das größte Element mit diesem Präfix zurück.
+    #[inline]
+    pub fn maximum(&self) -> T {
+        self.elements[self.elements.len() - 1]
+    }
+
+    /// Gibt alle Elemente mit diesem Präfix in aufsteigender Reihenfolge zurück.
+    #[inline]
+    pub fn elements(&self) -> &'a [T] {
+        self.elements
+    }
+
+    /// Gibt einen Iterator über die belegten Präfixe der L2-Ebene (zweiter Teil von `split_integer_down`) zurück.
+    pub fn children(&self) -> Children<'a, T> {
+        match self.level {
+            Some(level) => Children {
+                level: Some(level),
+                next: first_set_key(level),
+            },
+            None => Children {
+                level: None,
+                next: Some(Splittable::split_integer_down(&self.elements[0]).1),
+            },
+        }
+    }
+}
+
+/// Iterator über die belegten Präfixe einer L2-Ebene. Er wird von `Bucket::children` erzeugt.
+#[derive(Clone)]
+pub struct Children<'a, T> {
+    /// L2-Ebene, deren LX-Top-Tabelle durchlaufen wird. Bei None wird nur `next` zurückgegeben.
+    level: Option<&'a Level<L3Ebene<T>, T>>,
+
+    next: Option<LXKey>,
+}
+
+impl<'a, T> Iterator for Children<'a, T> {
+    type Item = LXKey;
+
+    fn next(&mut self) -> Option<LXKey> {
+        let key = self.next?;
+        self.next = self.level.and_then(|level| {
+            level
+                .lx_top()
+                .get_next_set_bit(key as usize)
+                .map(|x| x as LXKey)
+        });
+        Some(key)
+    }
+}
+
+impl<'a, T> std::iter::FusedIterator for Children<'a, T> {}
+
+/// Gibt den kleinsten belegten Schlüssel der Ebene `level` zurück.
+#[inline]
+fn first_set_key<T, E>(level: &Level<T, E>) -> Option<LXKey> {
+    if level.lx_top().is_set(0) {
+        Some(0)
+    } else {
+        level.lx_top().get_next_set_bit(0).map(|x| x as LXKey)
+    }
+}
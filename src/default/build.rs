@@ -1,244 +1,289 @@
-use crate::default::immutable::{Int, L2Ebene, LXKey, Level, LevelPointer, TopArray};
+use crate::default::immutable::{Int, L2Ebene, L3Ebene, LXKey, Level, LevelPointer, TopArray};
 use crate::internal::Splittable;
-use crate::internal::{self, PointerEnum};
-
-type HashMap<K, T> = hashbrown::hash_map::HashMap<K, T>;
 
 /// Gamma=2 wegen Empfehlung aus dem Paper. Wenn Hashen schneller werden soll, dann kann man bis gegen 5 gehen,
 /// Wenn die Struktur kleiner werden soll, kann man mal gamme=1 ausprobieren.
 pub const GAMMA: f64 = 2.0;
 
-/// Hilfsebene, die eine sehr starke Ähnlichkeit zur L2-Ebene hat.AsMut
-type L2EbeneBuilder<T> = internal::Pointer<BuilderLevel<L3EbeneBuilder<T>, T>, usize>;
+/// Fehler, die beim Prüfen der Eingabe eines STrees erkannt werden. `index` gibt jeweils die Position des
+/// fehlerhaften Elements in der übergebenen Liste an.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// Das Element an Position `index` ist kleiner als sein Vorgänger.
+    Unsorted { index: usize },
+
+    /// Das Element an Position `index` ist gleich seinem Vorgänger.
+    Duplicate { index: usize },
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BuildError::Unsorted { index } => {
+                write!(
+                    f,
+                    "Element an Position {} ist kleiner als sein Vorgänger",
+                    index
+                )
+            }
+            BuildError::Duplicate { index } => {
+                write!(f, "Element an Position {} kommt doppelt vor", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
 
-/// Hilfsebene, die eine sehr starke Ähnlichkeit zur L3-Ebene hat.AsMut
-type L3EbeneBuilder<T> = internal::Pointer<BuilderLevel<usize, T>, usize>;
+/// Prüft, ob `elements` sortiert ist und keine Duplikate enthält. Beim ersten fehlerhaften Element wird abgebrochen.
+/// Jeder Wert von `T` lässt sich in der `root_table` ablegen, sodass das Universum nicht geprüft werden muss.
+///
+/// # Arguments
+///
+/// * `elements` - Liste, die später an `STreeBuilder::new` übergeben werden soll.
+pub fn validate<T: Int>(elements: &[T]) -> Result<(), BuildError> {
+    validate_sorted(elements, false)
+}
 
-/// Hilfsdatenstruktur zum Bauen eines STrees (nötig wegen der perfekten Hashfunktionen, die zum Erzeugungszeitpunkt alle Schlüssel kennen müssen).
+/// Wie `validate`, allerdings sind mehrfach vorkommende Elemente erlaubt (siehe `STreeMultiset`).
+///
+/// # Arguments
+///
+/// * `elements` - Liste, die auf Sortierung geprüft werden soll.
+pub fn validate_multiset<T: Int>(elements: &[T]) -> Result<(), BuildError> {
+    validate_sorted(elements, true)
+}
+
+fn validate_sorted<T: Int>(elements: &[T], allow_duplicates: bool) -> Result<(), BuildError> {
+    for index in 1..elements.len() {
+        let (prev, element) = (&elements[index - 1], &elements[index]);
+        if element < prev {
+            return Err(BuildError::Unsorted { index });
+        } else if element == prev && !allow_duplicates {
+            return Err(BuildError::Duplicate { index });
+        }
+    }
+    Ok(())
+}
+
+/// Teilbereiche mit höchstens so vielen Elementen werden von `radix_sort` vergleichsbasiert sortiert.
+const RADIX_SORT_THRESHOLD: usize = 64;
+
+/// Sortiert `elements` in-place mittels MSD-Radixsort (American-Flag-Sort). Als Ziffern werden die Bytes von
+/// `split_integer_down().0` (höchstwertiges zuerst), gefolgt von j und k verwendet. Da der Root-Index genau
+/// `size_of::<T>() - 2` Bytes breit ist, gibt es keine Ziffern, die immer 0 sind. Es wird kein zusätzlicher Puffer in
+/// der Größe der Eingabe benötigt.
+///
+/// # Arguments
+///
+/// * `elements` - Unsortierte Liste, die sortiert werden soll. Duplikate bleiben erhalten.
+pub fn radix_sort<T: Int>(elements: &mut [T]) {
+    radix_sort_digit(elements, 0);
+}
+
+/// Gibt die `digit`-te 8-Bit-Ziffer (von der höchstwertigen aus gezählt) von `element` zurück.
+#[inline]
+fn radix_digit<T: Int>(element: &T, digit: usize) -> usize {
+    let (i, j, k) = Splittable::split_integer_down(element);
+    let root_digits = std::mem::size_of::<T>() - 2;
+    if digit < root_digits {
+        (i >> (8 * (root_digits - 1 - digit))) & 0xFF
+    } else if digit == root_digits {
+        j as usize
+    } else {
+        k as usize
+    }
+}
+
+fn radix_sort_digit<T: Int>(elements: &mut [T], digit: usize) {
+    if elements.len() <= RADIX_SORT_THRESHOLD {
+        elements.sort_unstable();
+        return;
+    }
+    if digit == std::mem::size_of::<T>() {
+        return;
+    }
+
+    let mut counts = [0_usize; 256];
+    for element in elements.iter() {
+        counts[radix_digit(element, digit)] += 1;
+    }
+
+    let mut starts = [0_usize; 256];
+    let mut ends = [0_usize; 256];
+    let mut sum = 0;
+    for ((start, end), &count) in starts.iter_mut().zip(ends.iter_mut()).zip(counts.iter()) {
+        *start = sum;
+        sum += count;
+        *end = sum;
+    }
+
+    // Jedes Element wird so lange in den Bucket seiner Ziffer getauscht, bis an der aktuellen Stelle ein passendes Element liegt.
+    let mut heads = starts;
+    for bucket in 0..256 {
+        while heads[bucket] < ends[bucket] {
+            let target = radix_digit(&elements[heads[bucket]], digit);
+            if target == bucket {
+                heads[bucket] += 1;
+            } else {
+                elements.swap(heads[bucket], heads[target]);
+                heads[target] += 1;
+            }
+        }
+    }
+
+    for ((&count, &start), &end) in counts.iter().zip(starts.iter()).zip(ends.iter()) {
+        if count > 1 {
+            radix_sort_digit(&mut elements[start..end], digit + 1);
+        }
+    }
+}
+
+/// Hilfsdatenstruktur zum Bauen eines STrees. Da die Eingabe sortiert ist, liegen die Elemente jeder Ebene
+/// zusammenhängend vor. Jede L3- und L2-Ebene wird daher direkt fertiggestellt, sobald sich ihr Präfix ändert,
+/// sodass keine Zwischenebenen mit normalen Hashfunktionen benötigt werden. Jedes Element wird dabei höchstens dreimal gelesen:
+/// beim Bestimmen des Endes seiner L2-Ebene, beim Bestimmen des Endes seiner L3-Ebene und beim Anlegen der Schlüssel
+/// seiner L3-Ebene.
+/// Die Eingabe wird dazu in Bereiche mit disjunkten Präfixen (`split_integer_down().0`) aufgeteilt, die unabhängig
+/// voneinander (mit dem Feature `rayon` parallel) gebaut werden.
 pub struct STreeBuilder<T> {
-    /// Mit Hilfe der ersten 24-Bits des zu speichernden Wortes wird in `root_table` eine L2EbeneBuilder je Eintrag abgelegt.
-    /// Dabei gilt `root_table: [L2Ebene;2^24]`
-    root_table: Box<[L2EbeneBuilder<T>]>,
+    /// Die fertigen Einträge der `root_table` mit ihrem Index, aufgeteilt nach den Bereichen, in denen sie gebaut wurden.
+    /// Innerhalb eines Bereichs und über alle Bereiche hinweg sind die Einträge aufsteigend nach Index sortiert.
+    parts: Vec<Vec<(usize, L2Ebene<T>)>>,
 
     /// Root-Top-Array
     root_top: Option<TopArray<T, usize>>,
-
-    /// Eine Liste, die alle belegten Indizes von `root_table` speichert.
-    root_indexs: Vec<usize>,
 }
 
 impl<T: Int> STreeBuilder<T> {
-    /// Gibt einen STreeBuilder mit den in `elements` enthaltenen Werten zurück. Dabei werden normale Hashfunktionen verwendet.
+    /// Gibt einen STreeBuilder mit den in `elements` enthaltenen Werten zurück. Dabei werden alle Ebenen samt ihrer
+    /// perfekten Hashfunktionen bereits angelegt. Die Elemente werden nur ausgeliehen, sodass der STree anschließend
+    /// `elements` selbst als Elementliste übernehmen kann.
     ///
     /// # Arguments
     ///
     /// * `elements` - Eine Liste mit sortierten u40-Werten, die in die statische Datenstruktur eingefügt werden sollten. Kein Wert darf doppelt vorkommen!
-    pub fn new(elements: Box<[T]>) -> Self {
-        let mut root_indexs = vec![];
-        let mut root_top: TopArray<T, usize> = TopArray::new();
-
-        // Hier wird ein root_array der Länge T::root_array_size() angelegt, was 2^i entspricht. Dabei entspricht bei einem u40 Integer i=40 .
-        let mut root_table: Box<[L2EbeneBuilder<T>]> =
-            vec![internal::Pointer::null(); T::root_array_size()].into_boxed_slice();
-
-        for (index, element) in elements.iter().enumerate() {
-            let (i, j, k) = Splittable::split_integer_down(element);
+    pub fn new(elements: &[T]) -> Self {
+        Self::with_parts(elements, build_parts())
+    }
 
-            if !root_top.is_set(i as usize) {
-                root_top.set_bit(i as usize);
-                root_indexs.push(i);
-            }
+    /// Wie `new`, die Eingabe wird aber in höchstens `parts` Bereiche aufgeteilt (siehe `partition`).
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Eine Liste mit sortierten Werten, die in die statische Datenstruktur eingefügt werden sollten. Kein Wert darf doppelt vorkommen!
+    /// * `parts` - Gewünschte Anzahl der Bereiche.
+    pub(crate) fn with_parts(elements: &[T], parts: usize) -> Self {
+        let ranges = Self::partition(elements, parts);
+        let parts = map_parts(ranges, |(start, end)| {
+            Self::build_range(elements, start, end)
+        });
 
-            if root_table[i].is_null() {
-                root_table[i] = internal::Pointer::from_second(Box::new(index));
-            } else {
-                match root_table[i].get() {
-                    PointerEnum::First(l) => {
-                        let second_level = l;
-                        second_level.maximum = index;
-
-                        if !second_level.lx_top.as_ref().unwrap().is_set(j as usize) {
-                            second_level.keys.push(j);
-
-                            let mut l3_level = internal::Pointer::null();
-                            Self::insert_l3_level(&mut l3_level, index, k, &elements);
-
-                            second_level.hash_map.insert(j, l3_level);
-                            second_level.lx_top.as_mut().unwrap().set_bit(j as usize);
-                        } else {
-                            // Hier fängt das unwrap() Implementierungsfehler ab, die den keys-Vektor nicht äquivalent zur Hashmap befüllen *outdated*
-                            Self::insert_l3_level(
-                                second_level.hash_map.get_mut(&j).unwrap(),
-                                index,
-                                k,
-                                &elements,
-                            );
-                        }
-                    }
-
-                    PointerEnum::Second(e) => {
-                        let (_, j2, k2) = Splittable::split_integer_down(&elements[*e]);
-                        let mut second_level = BuilderLevel::new();
-
-                        second_level.lx_top.as_mut().unwrap().set_bit(j as usize);
-                        // Minima- und Maximasetzung auf der ersten Ebene
-                        second_level.minimum = *e;
-                        second_level.maximum = index;
-
-                        let mut l3_level = internal::Pointer::null();
-
-                        if j2 != j {
-                            let mut l3_level = internal::Pointer::null();
-                            Self::insert_l3_level(&mut l3_level, *e, k2, &elements);
-
-                            second_level.keys.push(j2);
-                            second_level.hash_map.insert(j2, l3_level);
-                            second_level.lx_top.as_mut().unwrap().set_bit(j2 as usize)
-                        } else {
-                            Self::insert_l3_level(&mut l3_level, *e, k2, &elements);
-                        }
-
-                        // Reihenfolge der keys ist relevant!
-                        second_level.keys.push(j);
-                        Self::insert_l3_level(&mut l3_level, index, k, &elements);
-                        second_level.hash_map.insert(j, l3_level);
-
-                        root_table[i] = internal::Pointer::from_first(Box::new(second_level));
-                    }
-                }
-            }
+        let mut root_top: TopArray<T, usize> = TopArray::new();
+        for &(i, _) in parts.iter().flatten() {
+            root_top.set_bit(i);
         }
+
         Self {
-            root_table: root_table,
+            parts,
             root_top: Some(root_top),
-            root_indexs: root_indexs,
         }
     }
-    #[inline]
-    fn insert_l3_level(l3_level: &mut L3EbeneBuilder<T>, index: usize, k: LXKey, elements: &[T]) {
-        if l3_level.is_null() {
-            *l3_level = internal::Pointer::from_second(Box::new(index));
-        } else {
-            match l3_level.get() {
-                PointerEnum::First(l) => {
-                    let l3_level = l;
 
-                    debug_assert!(!l3_level.keys.contains(&k));
-
-                    l3_level.lx_top.as_mut().unwrap().set_bit(k as usize);
-                    l3_level.keys.push(k);
-
-                    //Maximasetzung auf der zweiten Ebene
-                    l3_level.maximum = index;
-
-                    l3_level.hash_map.insert(k, index);
-                }
-
-                PointerEnum::Second(e) => {
-                    let (_, _, k2) = Splittable::split_integer_down(&elements[*e]);
-                    let mut l3_level_n = BuilderLevel::new();
-                    l3_level_n.keys.push(k2);
-                    l3_level_n.keys.push(k);
+    /// Teilt `elements` in höchstens `parts` etwa gleich große, zusammenhängende Bereiche [start, end) auf. Die Grenzen
+    /// werden dabei so weit nach hinten verschoben, dass alle Elemente eines Präfixes im selben Bereich liegen.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Sortierte Eingabe des STrees.
+    /// * `parts` - Gewünschte Anzahl der Bereiche.
+    pub(crate) fn partition(elements: &[T], parts: usize) -> Vec<(usize, usize)> {
+        let parts = parts.max(1);
+        let step = elements.len().div_ceil(parts).max(1);
+        let mut ranges = vec![];
+        let mut start = 0;
+        while start < elements.len() {
+            let mut end = (start + step).min(elements.len());
+            if end < elements.len() {
+                let (i, _, _) = Splittable::split_integer_down(&elements[end - 1]);
+                end +=
+                    elements[end..].partition_point(|x| Splittable::split_integer_down(x).0 == i);
+            }
+            ranges.push((start, end));
+            start = end;
+        }
+        ranges
+    }
 
-                    debug_assert!(k2 != k);
+    /// Baut die Einträge der `root_table` für alle Präfixe der Elemente `elements[start..end]`. Die gespeicherten
+    /// Indizes beziehen sich auf die gesamte Liste `elements`.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Sortierte Eingabe des STrees.
+    /// * `start` - Index des ersten Elements des Bereichs.
+    /// * `end` - Index hinter dem letzten Element des Bereichs.
+    fn build_range(elements: &[T], start: usize, end: usize) -> Vec<(usize, L2Ebene<T>)> {
+        let mut result = vec![];
+        let mut l2_start = start;
+        while l2_start < end {
+            let (i, _, _) = Splittable::split_integer_down(&elements[l2_start]);
+            let l2_end = run_end(elements, l2_start, end, |x| {
+                Splittable::split_integer_down(x).0 == i
+            });
+            result.push((i, Self::build_l2_level(elements, l2_start, l2_end)));
+            l2_start = l2_end;
+        }
+        result
+    }
 
-                    // Minima- und Maximasetzung auf der zweiten Ebene
-                    l3_level_n.minimum = *e;
-                    l3_level_n.maximum = index;
+    /// Baut die L2-Ebene für die Elemente `elements[start..end]`, die alle dasselbe Präfix besitzen. Die L3-Ebenen
+    /// werden dabei nacheinander fertiggestellt, sobald sich der zweite Teil von `split_integer_down` ändert.
+    fn build_l2_level(elements: &[T], start: usize, end: usize) -> L2Ebene<T> {
+        if end - start == 1 {
+            return LevelPointer::from_usize(Box::new(start));
+        }
 
-                    l3_level_n.hash_map.insert(k2, *e);
-                    l3_level_n.hash_map.insert(k, index);
+        let mut entries = vec![];
+        let mut l3_start = start;
+        while l3_start < end {
+            let (_, j, _) = Splittable::split_integer_down(&elements[l3_start]);
+            let l3_end = run_end(elements, l3_start, end, |x| {
+                Splittable::split_integer_down(x).1 == j
+            });
+            entries.push((j, Self::build_l3_level(elements, l3_start, l3_end)));
+            l3_start = l3_end;
+        }
 
-                    l3_level_n.lx_top.as_mut().unwrap().set_bit(k as usize);
-                    l3_level_n.lx_top.as_mut().unwrap().set_bit(k2 as usize);
+        LevelPointer::from_level(Box::new(build_level(
+            entries,
+            LevelPointer::from_null,
+            start,
+            end - 1,
+        )))
+    }
 
-                    *l3_level = internal::Pointer::from_first(Box::new(l3_level_n));
-                }
-            }
+    /// Baut die L3-Ebene für die Elemente `elements[start..end]`, die in den ersten beiden Teilen von
+    /// `split_integer_down` übereinstimmen.
+    fn build_l3_level(elements: &[T], start: usize, end: usize) -> L3Ebene<T> {
+        if end - start == 1 {
+            return LevelPointer::from_usize(Box::new(start));
         }
+
+        let entries = (start..end)
+            .map(|index| (Splittable::split_integer_down(&elements[index]).2, index))
+            .collect();
+        LevelPointer::from_level(Box::new(build_level(entries, || 0, start, end - 1)))
     }
 
-    /// Baut ein Array `root_table` für den STree-Struct. Dabei werden zuerst die `Level`-Structs korrekt mittels neuer perfekter Hashfunktionen
-    /// angelegt und miteinander verbunden. Nachdem die Struktur mit normalen Hashfunktionen gebaut wurde können nun perfekte Hashfunktionen berechnet
-    /// werden!
+    /// Baut ein Array `root_table` für den STree-Struct, indem die in `new` gebauten Einträge an ihre Indizes verschoben
+    /// werden.
     pub fn build(&mut self) -> Box<[L2Ebene<T>]> {
-        let mut tmp: Vec<L2Ebene<T>> = Vec::with_capacity(T::root_array_size());
-        // Die L2Level-Elemente werden angelegt. Hierbei wird direkt in der new()-Funktion die perfekte Hashfunktion berechnet
-        for i in 0..tmp.capacity() {
-            if self.root_table[i].is_null() {
-                tmp.push(LevelPointer::from_null());
-            } else {
-                match self.root_table[i].get() {
-                    PointerEnum::First(l) => {
-                        let second_level = l;
-                        let objects: Vec<LevelPointer<usize, T>> =
-                            vec![LevelPointer::from_null(); second_level.keys.len()];
-                        let val = Box::new(Level::new(
-                            second_level.lx_top.take().unwrap(),
-                            objects.into_boxed_slice(),
-                            std::mem::replace(&mut second_level.keys, vec![]).into_boxed_slice(),
-                            second_level.minimum,
-                            second_level.maximum,
-                        ));
-                        tmp.push(LevelPointer::from_level(val));
-                    }
-
-                    PointerEnum::Second(e) => {
-                        tmp.push(LevelPointer::from_usize(Box::new(*e)));
-                    }
-                }
-            }
-        }
-        let result: Box<[L2Ebene<T>]> = tmp.into_boxed_slice();
-
-        for &i in &self.root_indexs {
-            // L3-Level werden nur angelegt, falls mehr als 1 Wert in der DS existiert.
-            if !result[i].is_null() {
-                match &mut result[i].get() {
-                    PointerEnum::First(l) => {
-                        // Hier muss l2_level aufgrund der symmetrischen Befüllung auch == Ptr::Level sein.LevelPointerBuilder
-                        match std::mem::replace(&mut self.root_table[i], L2EbeneBuilder::null())
-                            .get()
-                        {
-                            PointerEnum::First(l2) => {
-                                let l2_level = l2;
-                                let hm = std::mem::replace(&mut l2_level.hash_map, HashMap::new());
-                                for (j, l3_level) in hm.into_iter() {
-                                    if (*l).get(j).is_null() {
-                                        let pointered_data = (*l).get(j);
-
-                                        *pointered_data = match l3_level.get() {
-                                            PointerEnum::First(l2) => {
-                                                let l3_level = l2;
-                                                let mut level = Level::new(
-                                                    l3_level.lx_top.take().unwrap(),
-                                                    vec![0; l3_level.keys.len()].into_boxed_slice(),
-                                                    std::mem::replace(&mut l3_level.keys, vec![]).into_boxed_slice(),
-                                                    l3_level.minimum,
-                                                    l3_level.maximum,
-                                                );
-                                                let hm = std::mem::replace(&mut l3_level.hash_map, HashMap::new());
-                                                for (k,val) in hm.into_iter() {
-                                                    let result = level.get(k);
-                                                    *result = val;
-                                                }
-
-                                                LevelPointer::from_level(Box::new(level))
-                                            }
-                                            PointerEnum::Second(e) => {
-                                                LevelPointer::from_usize(Box::new(*e))
-                                            }
-                                        };
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-
-                    _ => {}
-                }
-            }
+        let mut result: Box<[L2Ebene<T>]> = (0..T::root_array_size())
+            .map(|_| LevelPointer::from_null())
+            .collect();
+        for (i, l2_level) in std::mem::take(&mut self.parts).into_iter().flatten() {
+            result[i] = l2_level;
         }
         result
     }
@@ -248,42 +293,69 @@ impl<T: Int> STreeBuilder<T> {
     }
 }
 
-/// Zwischenschicht zwischen dem Root-Array und des Element-Arrays.
-#[derive(Clone)]
-pub struct BuilderLevel<T, E> {
-    /// Klassische HashMap zum aufbauen der perfekten Hashmap
-    pub hash_map: HashMap<LXKey, T>,
+/// Gibt den Index hinter dem letzten Element ab `start` zurück, für das `same` gilt. Es wird höchstens bis `end` gesucht.
+#[inline]
+fn run_end<T, F: Fn(&T) -> bool>(elements: &[T], start: usize, end: usize, same: F) -> usize {
+    let mut index = start + 1;
+    while index < end && same(&elements[index]) {
+        index += 1;
+    }
+    index
+}
 
-    /// Eine Liste aller bisher gesammelter Schlüssel, die später auf die nächste Ebene zeigen.
-    /// Diese werden zur Erzeugung der perfekten Hashfunktion benötigt.
-    pub keys: Vec<LXKey>,
+/// Legt eine Ebene mit perfekter Hashfunktion über die Schlüssel aus `entries` an. Anschließend wird jedes Objekt an
+/// der Position abgelegt, auf die die Hashfunktion seinen Schlüssel abbildet.
+///
+/// # Arguments
+///
+/// * `entries` - Aufsteigend sortierte Schlüssel mit den zugehörigen Objekten der nächsten Ebene.
+/// * `placeholder` - Erzeugt die Objekte, mit denen die Ebene bis zum Ablegen von `entries` gefüllt wird.
+/// * `minimum` - Index des Minimums der Ebene in der Elementliste.
+/// * `maximum` - Index des Maximums der Ebene in der Elementliste.
+fn build_level<O, E, P: Fn() -> O>(
+    entries: Vec<(LXKey, O)>,
+    placeholder: P,
+    minimum: usize,
+    maximum: usize,
+) -> Level<O, E> {
+    let mut lx_top = TopArray::new();
+    for &(key, _) in &entries {
+        lx_top.set_bit(key as usize);
+    }
+    let keys: Box<[LXKey]> = entries.iter().map(|&(key, _)| key).collect();
+    let objects: Box<[O]> = entries.iter().map(|_| placeholder()).collect();
 
-    /// Speichert die L2-, bzw. L3-Top-Tabelle, welche 2^8 (Bits) besitzt. Also [u64;2^8/64].
-    /// Dabei ist ein Bit lx_top[x]=1 gesetzt, wenn x ein Schlüssel für die perfekte Hashfunktion ist und in objects[hash_function.hash(x)] mindestens ein Wert gespeichert ist.
-    /// Dieses Array wird später an den `Level`-Struct weitergegeben
-    lx_top: Option<TopArray<E, u8>>,
+    let mut level = Level::new(lx_top, objects, keys, minimum, maximum);
+    for (key, object) in entries {
+        *level.get_mut(key) = object;
+    }
+    level
+}
 
-    /// Speichert das Maximum des Levels zwischen
-    pub maximum: usize,
+/// Anzahl der Bereiche, in die `STreeBuilder` die Eingabe aufteilt. Mit dem Feature `rayon` werden mehr Bereiche als
+/// Threads erzeugt, damit unterschiedlich stark belegte Präfixe die Last nicht zu ungleich verteilen.
+#[cfg(feature = "rayon")]
+fn build_parts() -> usize {
+    rayon::current_num_threads() * 4
+}
 
-    /// Speichert das Minimum des Levels zwischen
-    pub minimum: usize,
+/// Anzahl der Bereiche, in die `STreeBuilder` die Eingabe aufteilt. Ohne das Feature `rayon` wird sequentiell gebaut.
+#[cfg(not(feature = "rayon"))]
+fn build_parts() -> usize {
+    1
 }
 
-impl<T, E> BuilderLevel<T, E> {
-    /// Gibt ein BuilderLevel<T> zurück.
-    ///
-    /// # Arguments
-    ///
-    /// * `lx_top_size` - Gibt die Länge des Arrays `lx_top_size` an.
-    #[inline]
-    pub fn new() -> BuilderLevel<T, E> {
-        BuilderLevel {
-            hash_map: HashMap::new(),
-            keys: vec![],
-            lx_top: Some(TopArray::new()),
-            maximum: 0,
-            minimum: 1,
-        }
+/// Wendet `f` auf alle Bereiche aus `parts` an und gibt die Ergebnisse in derselben Reihenfolge zurück. Mit dem
+/// Feature `rayon` werden die Bereiche parallel abgearbeitet.
+fn map_parts<P: Send, R: Send, F: Fn(P) -> R + Send + Sync>(parts: Vec<P>, f: F) -> Vec<R> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        parts.into_par_iter().map(f).collect()
     }
-}
\ No newline at end of file
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        parts.into_iter().map(f).collect()
+    }
+}
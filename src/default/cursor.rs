@@ -0,0 +1,160 @@
+use crate::default::immutable::{Int, STree};
+
+/// Größe des Fensters der Elementliste, das `Cursor::seek` und `Cursor::seek_back` ausgehend von der aktuellen Position
+/// binär durchsuchen, bevor erneut über `locate_or_succ` bzw. `locate_or_pred` abgestiegen wird.
+const LOCAL_SEEK_WINDOW: usize = 16;
+
+/// Ein Cursor zeigt auf eine Position in der Elementliste eines STrees und erlaubt es, von dort aus die Nachbarn
+/// zu besuchen. Neben den Positionen 0..len() gibt es eine Geisterposition, die zugleich vor dem Minimum und hinter dem
+/// Maximum liegt. Auf ihr liefern `key` und `index` None. Von der Geisterposition aus springt `next` auf das Minimum
+/// und `prev` auf das Maximum.
+#[derive(Clone)]
+pub struct Cursor<'a, T> {
+    tree: &'a STree<T>,
+
+    /// Aktueller Index in der Elementliste. Der Wert `tree.len()` steht für die Geisterposition.
+    index: usize,
+}
+
+impl<'a, T: Int> Cursor<'a, T> {
+    /// Gibt einen Cursor auf `index` zurück. Ist `index` >= `tree.len()`, steht der Cursor auf der Geisterposition.
+    #[inline]
+    pub(crate) fn new(tree: &'a STree<T>, index: usize) -> Self {
+        Self {
+            tree,
+            index: index.min(tree.len()),
+        }
+    }
+
+    /// Gibt das Element an der aktuellen Position zurück.
+    #[inline]
+    pub fn key(&self) -> Option<T> {
+        self.tree.element_list.get(self.index).copied()
+    }
+
+    /// Gibt den Index der aktuellen Position in der Elementliste zurück.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        if self.index < self.tree.len() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+
+    /// Gibt das Element hinter der aktuellen Position zurück, ohne den Cursor zu bewegen.
+    #[inline]
+    pub fn peek(&self) -> Option<T> {
+        if self.index >= self.tree.len() {
+            self.tree.element_list.first().copied()
+        } else {
+            self.tree.element_list.get(self.index + 1).copied()
+        }
+    }
+
+    /// Gibt das Element vor der aktuellen Position zurück, ohne den Cursor zu bewegen.
+    #[inline]
+    pub fn peek_prev(&self) -> Option<T> {
+        if self.index >= self.tree.len() {
+            self.tree.element_list.last().copied()
+        } else if self.index == 0 {
+            None
+        } else {
+            Some(self.tree.element_list[self.index - 1])
+        }
+    }
+
+    /// Bewegt den Cursor um eine Position zurück und gibt das dortige Element zurück.
+    #[inline]
+    pub fn prev(&mut self) -> Option<T> {
+        let len = self.tree.len();
+        self.index = match self.index {
+            0 => len,
+            i if i >= len => len - 1,
+            i => i - 1,
+        };
+        self.key()
+    }
+
+    /// Setzt den Cursor auf das kleinste Element, das größer oder gleich `element` ist. Existiert keines, steht der
+    /// Cursor anschließend auf der Geisterposition. Liegt das Ziel in der Nähe der aktuellen Position, wird nicht über
+    /// die Root-Tabelle abgestiegen.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, dessen Nachfolger (oder er selbst) angesteuert werden soll.
+    pub fn seek(&mut self, element: T) {
+        let len = self.tree.len();
+        if self.index < len {
+            let (lo, hi) = self.window(element);
+            let window = &self.tree.element_list[lo..hi];
+            // Anzahl der Elemente im Fenster, die echt kleiner als `element` sind
+            let pos = match window.binary_search(&element) {
+                Ok(pos) | Err(pos) => pos,
+            };
+            // Liegt das Ergebnis am Rand des Fensters, kann es auch außerhalb liegen.
+            if (pos > 0 || lo == 0) && (pos < window.len() || hi == len) {
+                self.index = lo + pos;
+                return;
+            }
+        }
+        self.index = self.tree.locate_or_succ(element).unwrap_or(len);
+    }
+
+    /// Setzt den Cursor auf das größte Element, das kleiner oder gleich `element` ist. Existiert keines, steht der
+    /// Cursor anschließend auf der Geisterposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, dessen Vorgänger (oder er selbst) angesteuert werden soll.
+    pub fn seek_back(&mut self, element: T) {
+        let len = self.tree.len();
+        if self.index < len {
+            let (lo, hi) = self.window(element);
+            let window = &self.tree.element_list[lo..hi];
+            // Anzahl der Elemente im Fenster, die kleiner oder gleich `element` sind
+            let pos = match window.binary_search(&element) {
+                Ok(pos) => pos + 1,
+                Err(pos) => pos,
+            };
+            if pos == 0 && lo == 0 {
+                self.index = len;
+                return;
+            } else if pos > 0 && (pos < window.len() || hi == len) {
+                self.index = lo + pos - 1;
+                return;
+            }
+        }
+        self.index = self.tree.locate_or_pred(element).unwrap_or(len);
+    }
+
+    /// Gibt das Fenster [lo, hi) der Elementliste zurück, das ausgehend von der aktuellen (gültigen) Position in
+    /// Richtung `element` durchsucht wird.
+    #[inline]
+    fn window(&self, element: T) -> (usize, usize) {
+        if self.tree.element_list[self.index] < element {
+            (
+                self.index,
+                (self.index + LOCAL_SEEK_WINDOW).min(self.tree.len()),
+            )
+        } else {
+            (self.index.saturating_sub(LOCAL_SEEK_WINDOW), self.index + 1)
+        }
+    }
+}
+
+/// Der Cursor kann als Iterator verwendet werden. `next` bewegt ihn um eine Position weiter und gibt das dortige
+/// Element zurück. Erreicht er die Geisterposition, wird einmal None geliefert.
+impl<'a, T: Int> Iterator for Cursor<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.index = if self.index >= self.tree.len() {
+            0
+        } else {
+            self.index + 1
+        };
+        self.key()
+    }
+}
@@ -0,0 +1,160 @@
+use crate::default::immutable::{Int, STree};
+use crate::internal::Splittable;
+
+impl<T: Int> STree<T> {
+    /// Prüft, ob im geschlossenen Intervall [`from`, `to`] kein Element gespeichert ist. Für `from` > `to` ist das
+    /// Intervall leer und es wird true zurückgegeben.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Untere Grenze (inklusiv).
+    /// * `to` - Obere Grenze (inklusiv).
+    pub fn is_range_empty(&self, from: T, to: T) -> bool {
+        if from > to {
+            return true;
+        }
+        match self.locate_or_succ(from) {
+            Some(index) => self.element_list[index] > to,
+            None => true,
+        }
+    }
+
+    /// Gibt die größte Lücke, also das längste geschlossene Intervall [start, end] ungenutzter Werte des Universums,
+    /// zurück. Bei mehreren gleich großen Lücken wird die erste zurückgegeben. Ist das Universum vollständig belegt,
+    /// wird None zurückgegeben.
+    /// Die Wurzel-Ebenen werden über `root_top` übersprungen. Innerhalb einer Wurzel-Ebene wird die Elementliste nur dann
+    /// durchsucht, wenn die Spanne zwischen deren Minimum und Maximum eine größere Lücke als die bisher beste zulässt.
+    pub fn largest_gap(&self) -> Option<(T, T)> {
+        let universe_maximum = Self::universe_maximum();
+        if self.is_empty() {
+            return Some((T::from(0), T::from(universe_maximum)));
+        }
+
+        // Beste Lücke als (start, end), jeweils inklusiv.
+        let mut best: Option<(u64, u64)> = None;
+        let first: u64 = self.element_list[0].into();
+        if first > 0 {
+            consider(&mut best, 0, first - 1);
+        }
+
+        let mut i = Splittable::split_integer_down(&self.element_list[0]).0;
+        loop {
+            let minimum = self.root_table[i].minimum();
+            let maximum = self.root_table[i].maximum();
+            let low: u64 = self.element_list[minimum].into();
+            let high: u64 = self.element_list[maximum].into();
+
+            // Eine Lücke innerhalb der Ebene umfasst höchstens high - low - 1 Werte.
+            if high - low >= 2 && !matches!(best, Some((s, e)) if high - low - 2 <= e - s) {
+                for pair in self.element_list[minimum..=maximum].windows(2) {
+                    let (a, b): (u64, u64) = (pair[0].into(), pair[1].into());
+                    if b - a >= 2 {
+                        consider(&mut best, a + 1, b - 1);
+                    }
+                }
+            }
+
+            match self.root_top.get_next_set_bit(i) {
+                Some(next) => {
+                    let b: u64 = self.element_list[self.root_table[next].minimum()].into();
+                    if b - high >= 2 {
+                        consider(&mut best, high + 1, b - 1);
+                    }
+                    i = next;
+                }
+                None => {
+                    if high < universe_maximum {
+                        consider(&mut best, high + 1, universe_maximum);
+                    }
+                    break;
+                }
+            }
+        }
+
+        best.map(|(start, end)| (T::from(start), T::from(end)))
+    }
+
+    /// Gibt den kleinsten Wert `start` >= `after` zurück, sodass die `len` Werte ab `start` alle ungenutzt sind.
+    /// Existiert keine solche Lücke innerhalb des Universums, wird None zurückgegeben.
+    /// Wurzel-Ebenen, deren Spanne zu klein für eine passende Lücke ist, werden als Ganzes übersprungen.
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - Kleinster zulässiger Startwert der Lücke.
+    /// * `len` - Mindestanzahl aufeinanderfolgender ungenutzter Werte.
+    pub fn first_gap_at_least(&self, after: T, len: u64) -> Option<T> {
+        let universe_maximum = Self::universe_maximum();
+        let after_value: u64 = after.into();
+        if len == 0 {
+            return Some(after);
+        }
+
+        // Prüft, ob zwischen `start` und dem Ende des Universums noch `len` Werte liegen.
+        let tail = |start: u64| {
+            if universe_maximum - start >= len - 1 {
+                Some(T::from(start))
+            } else {
+                None
+            }
+        };
+
+        let mut index = match self.locate_or_succ(after) {
+            Some(index) => index,
+            None => return tail(after_value),
+        };
+        let first: u64 = self.element_list[index].into();
+        if first - after_value >= len {
+            return Some(after);
+        }
+
+        // Die Lücke muss nun hinter element_list[index] beginnen.
+        loop {
+            let i = Splittable::split_integer_down(&self.element_list[index]).0;
+            let maximum = self.root_table[i].maximum();
+            let low: u64 = self.element_list[index].into();
+            let high: u64 = self.element_list[maximum].into();
+
+            // Zwischen low und high liegen höchstens high - low - 1 ungenutzte Werte.
+            if high - low > len {
+                for pair in self.element_list[index..=maximum].windows(2) {
+                    let (a, b): (u64, u64) = (pair[0].into(), pair[1].into());
+                    if b - a > len {
+                        return Some(T::from(a + 1));
+                    }
+                }
+            }
+
+            match self.root_top.get_next_set_bit(i) {
+                Some(next) => {
+                    let next_index = self.root_table[next].minimum();
+                    let b: u64 = self.element_list[next_index].into();
+                    if b - high > len {
+                        return Some(T::from(high + 1));
+                    }
+                    index = next_index;
+                }
+                None => {
+                    if high == universe_maximum {
+                        return None;
+                    }
+                    return tail(high + 1);
+                }
+            }
+        }
+    }
+
+    /// Gibt den größten Wert des Universums von `T` zurück.
+    #[inline]
+    fn universe_maximum() -> u64 {
+        ((T::root_array_size() as u64) << 16).wrapping_sub(1)
+    }
+}
+
+/// Übernimmt die Lücke [`start`, `end`] als neue beste Lücke, falls sie echt größer als `best` ist.
+#[inline]
+fn consider(best: &mut Option<(u64, u64)>, start: u64, end: u64) {
+    match *best {
+        Some((s, e)) if end - start <= e - s => {}
+        _ => *best = Some((start, end)),
+    }
+}
@@ -1,7 +1,10 @@
 use uint::{u40, u48};
 
-use crate::default::build::STreeBuilder;
-use crate::internal::{MphfHashMap, Splittable};
+use crate::default::build::{self, BuildError, STreeBuilder};
+use crate::default::cursor::Cursor;
+use crate::default::iter::Range;
+use crate::internal::{MphfHashMap, Splittable, StaticPredecessorSet};
+use std::ops::{Bound, RangeBounds};
 /// Die L2-Ebene ist eine Zwischenebene, die mittels eines u8-Integers und einer perfekten Hashfunktion auf eine
 /// L3-Ebene zeigt.
 pub type L2Ebene<T> = LevelPointer<L3Ebene<T>, T>;
@@ -42,14 +45,32 @@ impl<T, E> LevelPointer<T, E> {
         }
     }
 
-    pub fn get(&self) -> PointerEnum<Level<T, E>, usize> {
+    pub(crate) fn get(&self) -> PointerEnum<'_, Level<T, E>, usize> {
         self.pointer.get()
     }
 
+    /// Gibt eine lesende Sicht auf die Ebene bzw. den Index zurück, auf den gezeigt wird.
+    #[inline]
+    pub fn view(&self) -> LevelView<'_, T, E> {
+        if self.is_null() {
+            return LevelView::Empty;
+        }
+        match self.pointer.get() {
+            PointerEnum::First(l) => LevelView::Inner(l),
+            PointerEnum::Second(e) => LevelView::Singleton(*e),
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         self.pointer.is_null()
     }
 
+    /// Gibt die Adresse der Ebene bzw. des Index zurück, auf den gezeigt wird. Sie dient nur dem Prefetching.
+    #[inline]
+    pub(crate) fn address(&self) -> *const u8 {
+        self.pointer.address()
+    }
+
     pub fn from_null() -> Self {
         Self {
             pointer: internal::Pointer::null(),
@@ -67,29 +88,50 @@ impl<T, E> LevelPointer<T, E> {
     }
 }
 
+/// Lesende Sicht auf einen Eintrag der `root_table` bzw. einer L2-Ebene. Sie wird von `STree::root` und
+/// `LevelPointer::view` zurückgegeben und erlaubt keinen schreibenden Zugriff auf die Ebenen.
+pub enum LevelView<'a, T, E> {
+    /// Unter diesem Präfix ist kein Element gespeichert.
+    Empty,
+
+    /// Unter diesem Präfix ist genau ein Element gespeichert. Der Wert ist dessen Index in der Elementliste.
+    Singleton(usize),
+
+    /// Unter diesem Präfix sind mehrere Elemente gespeichert, die über die nächste Ebene erreicht werden.
+    Inner(&'a Level<T, E>),
+}
+
+impl<'a, T, E> Clone for LevelView<'a, T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, E> Copy for LevelView<'a, T, E> {}
+
 /// Statische Predecessor-Datenstruktur. Sie verwendet perfektes Hashing und ein Array auf der Element-Listen-Ebene.
 /// Sie kann nur sortierte und einmalige Elemente entgegennehmen.
 #[derive(Clone)]
 pub struct STree<T> {
     /// Mit Hilfe der ersten 24-Bits des zu speichernden Wortes wird in `root_table` eine L2-Ebene je Eintrag abgelegt.
     /// Dabei gilt `root_table: [L2Ebene;2^24]`
-    pub root_table: Box<[L2Ebene<T>]>,
+    pub(crate) root_table: Box<[L2Ebene<T>]>,
 
     /// Das Root-Top-Array speichert für jeden Eintrag `root_table[i][x]`, der belegt ist, ein 1-Bit, sonst einen 0-Bit.
     /// Auch hier werden nicht 2^24 Einträge, sondern lediglich [u64;2^24/64] gespeichert.
     /// i steht dabei für die Ebene der root_tabelle. Ebene i+1 beinhaltet an Index [x] immer 64 Veroderungen aus Ebene i.
     /// Somit gilt |root_table[i+1]| = |root_table[i]|/64  
-    pub root_top: TopArray<T, usize>,
+    pub(crate) root_top: TopArray<T, usize>,
 
     /// Die Elementliste beinhaltet einen Vektor konstanter Länge mit jeweils allen gespeicherten Elementen in sortierter Reihenfolge.
-    pub element_list: Box<[T]>,
+    pub(crate) element_list: Box<[T]>,
 }
 
 /// Liste von Bitarrays zur Speicherung der LX-Top-Datenstrukturen
-/// Zur Speicherplatzreduzierung werden die Längen der Arrays weggeworfen und zum Drop-Zeitpunkt erneut berechnet
+#[derive(Clone)]
 pub struct TopArray<T, V> {
-    /// 2-dimensionales Array mit
-    data: Box<[*mut u64]>,
+    /// 2-dimensionales Array mit einem Bitarray je Ebene. Ebene i+1 beinhaltet an Index [x] immer 64 Veroderungen aus Ebene i.
+    data: Box<[Box<[u64]>]>,
 
     // Länge der untersten Ebene. Kleiner Tradeoff zwischen Länge aller Ebenen Speichern und Level der tiefsten Ebene Speichern...
     lowest_len: usize,
@@ -101,49 +143,6 @@ pub struct TopArray<T, V> {
     phantom_type: std::marker::PhantomData<V>,
 }
 
-impl<T, V> Drop for TopArray<T, V> {
-    fn drop(&mut self) {
-        // Hier muss editiert werden, wenn die Größen der L2- und L3- Level angepasst werden sollen
-        let mut length = Self::get_length();
-
-        // Solange Länge / 2^i > 256
-        for (i, &ptr) in self.data.into_iter().enumerate() {
-            length = length >> (i + 1) * 6;
-            unsafe {
-                Box::from_raw(std::slice::from_raw_parts_mut(ptr, length));
-            }
-        }
-    }
-}
-
-impl<T, V> Clone for TopArray<T, V> {
-    fn clone(&self) -> Self {
-        // Hier muss editiert werden, wenn die Größen der L2- und L3- Level angepasst werden sollen
-        let mut length = Self::get_length();
-
-        // Lege alle Rootarrays an
-        let mut top_arrays = vec![];
-
-        // Solange Länge / 2^i > 256
-        for (i, &ptr) in self.data.iter().enumerate() {
-            length = length >> (i + 1) * 6;
-            let mut tmp = vec![];
-            unsafe {
-                for i in 0..length {
-                    tmp.push(*ptr.add(i));
-                }
-            }
-            top_arrays.push(Box::into_raw(tmp.into_boxed_slice()) as *mut u64);
-        }
-        Self {
-            data: top_arrays.into_boxed_slice(),
-            lowest_len: length,
-            phantom: std::marker::PhantomData,
-            phantom_type: std::marker::PhantomData,
-        }
-    }
-}
-
 impl<T, V> TopArray<T, V> {
     #[inline]
     fn get_length() -> usize {
@@ -169,7 +168,7 @@ impl<T, V> TopArray<T, V> {
         // Solange Länge / 64^i > 64
         while length >= 64 {
             length = length >> 6;
-            top_arrays.push(Box::into_raw(vec![0_u64; length].into_boxed_slice()) as *mut u64);
+            top_arrays.push(vec![0_u64; length].into_boxed_slice());
         }
 
         Self {
@@ -180,6 +179,18 @@ impl<T, V> TopArray<T, V> {
         }
     }
 
+    /// Erzeugt ein TopArray ohne Ebenen. Dieses wird nur von leeren STrees verwendet und darf nicht angefragt werden.
+    /// Deshalb ist es außerhalb des Crates nicht sichtbar.
+    #[inline]
+    pub(crate) fn empty() -> Self {
+        Self {
+            data: vec![].into_boxed_slice(),
+            lowest_len: 0,
+            phantom: std::marker::PhantomData,
+            phantom_type: std::marker::PhantomData,
+        }
+    }
+
     #[inline]
     const fn get_bit_mask(in_index: usize) -> u64 {
         1 << 63 - in_index
@@ -195,14 +206,12 @@ impl<T, V> TopArray<T, V> {
         for i in 0..(self.data.len()) {
             // Aktueller in_index wird für Bitmaske verwendet
             let bit_mask = Self::get_bit_mask(in_index);
-            let bit_window = unsafe { self.data.get_unchecked(i).add(index) };
+            let bit_window = &mut self.data[i][index];
 
             in_index = index % 64;
             index = index / 64;
 
-            unsafe {
-                *bit_window = *bit_window | bit_mask;
-            }
+            *bit_window |= bit_mask;
         }
     }
 
@@ -210,16 +219,16 @@ impl<T, V> TopArray<T, V> {
     pub fn is_set(&self, bit: usize) -> bool {
         let (index, in_index) = (bit / 64, bit % 64);
         let bit_mask = Self::get_bit_mask(in_index);
-        let bit_window = unsafe { self.data.get_unchecked(0).add(index) };
+        let bit_window = &self.data[0][index];
 
-        unsafe { *bit_window & bit_mask != 0 }
+        *bit_window & bit_mask != 0
     }
 
     #[inline]
     fn get_next_set_bit_translation(&self, index: usize, last_level: usize) -> usize {
         let mut index = index;
         for i in (0..(last_level)).rev() {
-            let zeros_to_bit = unsafe { *self.data.get_unchecked(i).add(index) };
+            let zeros_to_bit = self.data[i][index];
             index = index * 64 + zeros_to_bit.leading_zeros() as usize;
         }
         index
@@ -236,7 +245,7 @@ impl<T, V> TopArray<T, V> {
             let bit_mask: u64 = u64::max_value()
                 .checked_shr(in_index as u32 + 1)
                 .unwrap_or(0);
-            let zeros_to_bit = unsafe { *self.data.get_unchecked(level).add(index) & bit_mask };
+            let zeros_to_bit = self.data[level][index] & bit_mask;
 
             if zeros_to_bit != 0 {
                 let zeros = zeros_to_bit.leading_zeros() as usize;
@@ -254,8 +263,7 @@ impl<T, V> TopArray<T, V> {
         let bit_mask: u64 = u64::max_value()
             .checked_shr(in_index as u32 + 1)
             .unwrap_or(0);
-        let mut zeros_to_bit =
-            unsafe { (*self.data.get_unchecked(self.data.len() - 1).add(index)) & bit_mask };
+        let mut zeros_to_bit = self.data[self.data.len() - 1][index] & bit_mask;
 
         for i in (index)..self.lowest_len {
             if zeros_to_bit != 0 {
@@ -266,7 +274,7 @@ impl<T, V> TopArray<T, V> {
             }
 
             if i < self.lowest_len - 1 {
-                zeros_to_bit = unsafe { *self.data.get_unchecked(self.data.len() - 1).add(i + 1) };
+                zeros_to_bit = self.data[self.data.len() - 1][i + 1];
             }
         }
         None
@@ -276,7 +284,7 @@ impl<T, V> TopArray<T, V> {
     fn get_prev_set_bit_translation(&self, index: usize, last_level: usize) -> usize {
         let mut index = index;
         for i in (0..(last_level)).rev() {
-            let zeros_to_bit = unsafe { *self.data.get_unchecked(i).add(index) };
+            let zeros_to_bit = self.data[i][index];
             index = index * 64 + 63 - zeros_to_bit.trailing_zeros() as usize;
         }
         index
@@ -294,7 +302,7 @@ impl<T, V> TopArray<T, V> {
                 .checked_shl(64 - in_index as u32)
                 .unwrap_or(0);
 
-            let zeros_to_bit = unsafe { *self.data.get_unchecked(level).add(index) & bit_mask };
+            let zeros_to_bit = self.data[level][index] & bit_mask;
             if zeros_to_bit != 0 {
                 let zeros = zeros_to_bit.trailing_zeros();
 
@@ -314,8 +322,7 @@ impl<T, V> TopArray<T, V> {
         let bit_mask: u64 = u64::max_value()
             .checked_shl(64 - in_index as u32)
             .unwrap_or(0);
-        let mut zeros_to_bit =
-            unsafe { (*self.data.get_unchecked(self.data.len() - 1).add(index)) & bit_mask };
+        let mut zeros_to_bit = self.data[self.data.len() - 1][index] & bit_mask;
 
         for i in (0..(index + 1)).rev() {
             if zeros_to_bit != 0 {
@@ -326,7 +333,7 @@ impl<T, V> TopArray<T, V> {
             }
 
             if i > 0 {
-                zeros_to_bit = unsafe { *self.data.get_unchecked(self.data.len() - 1).add(i - 1) };
+                zeros_to_bit = self.data[self.data.len() - 1][i - 1];
             }
         }
 
@@ -337,7 +344,7 @@ impl<T, V> TopArray<T, V> {
 /// Dieser Trait dient als Platzhalter für u40, u48 und u64.
 /// Er stellt sicher das der generische Parameter gewisse Traits implementiert und die New-Methode besitzt.
 /// Zusätzlich wird die Größe des Root-Arrays in Form einer Funktion rückgebar gemacht.
-pub trait Int: Ord + PartialOrd + From<u64> + Into<u64> + Copy + Splittable {
+pub trait Int: Ord + PartialOrd + From<u64> + Into<u64> + Copy + Send + Sync + Splittable {
     fn new(k: u64) -> Self {
         Self::from(k)
     }
@@ -361,7 +368,11 @@ impl<T: Int> STree<T> {
     /// * `elements` - Eine Liste mit sortierten u40-Werten, die in die statische Datenstruktur eingefügt werden sollten. Kein Wert darf doppelt vorkommen!
     #[inline]
     pub fn new(elements: Box<[T]>) -> Self {
-        let mut builder = STreeBuilder::<T>::new(elements.clone());
+        if elements.is_empty() {
+            return Self::empty();
+        }
+
+        let mut builder = STreeBuilder::<T>::new(&elements);
 
         let root_top = builder.get_root_top();
         STree {
@@ -371,12 +382,54 @@ impl<T: Int> STree<T> {
         }
     }
 
+    /// Gibt einen STree mit den in `elements` enthaltenen Werten zurück. Im Gegensatz zu `new` wird die Eingabe
+    /// vorher geprüft, sodass unsortierte Listen und Duplikate einen `BuildError` liefern.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Eine Liste mit sortierten Werten, die in die statische Datenstruktur eingefügt werden sollten.
+    #[inline]
+    pub fn try_new(elements: Box<[T]>) -> Result<Self, BuildError> {
+        build::validate(&elements)?;
+        Ok(Self::new(elements))
+    }
+
+    /// Gibt einen STree mit den in `elements` enthaltenen Werten zurück. Die Werte dürfen unsortiert sein und mehrfach
+    /// vorkommen. Sie werden in-place mittels Radixsort sortiert und anschließend von Duplikaten befreit.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Eine beliebige Liste von Werten, die in die statische Datenstruktur eingefügt werden sollten.
+    #[inline]
+    pub fn from_unsorted(mut elements: Vec<T>) -> Self {
+        build::radix_sort(&mut elements);
+        elements.dedup();
+        Self::new(elements.into_boxed_slice())
+    }
+
+    /// Gibt einen leeren STree zurück. Dabei werden weder die `root_table` noch das Root-Top-Array angelegt,
+    /// sodass das Erzeugen unabhängig vom Universum keinen nennenswerten Speicher benötigt.
+    #[inline]
+    pub fn empty() -> Self {
+        STree {
+            root_table: vec![].into_boxed_slice(),
+            root_top: TopArray::empty(),
+            element_list: vec![].into_boxed_slice(),
+        }
+    }
+
     /// Gibt die Anzahl der in self enthaltenen Elemente zurück.
     #[inline]
     pub fn len(&self) -> usize {
         self.element_list.len()
     }
 
+    /// Gibt zurück, ob die Datenstruktur leer ist.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.element_list.is_empty()
+    }
+
     /// Gibt das in der Datenstruktur gespeicherte Minimum zurück. Falls die Datenstruktur leer ist, wird None zurückgegeben.
     #[inline]
     pub fn minimum(&self) -> Option<T> {
@@ -395,6 +448,164 @@ impl<T: Int> STree<T> {
         Some(self.element_list[self.len() - 1])
     }
 
+    /// Gibt alle gespeicherten Elemente in aufsteigender Reihenfolge zurück.
+    #[inline]
+    pub fn element_list(&self) -> &[T] {
+        &self.element_list
+    }
+
+    /// Gibt eine lesende Sicht auf den Eintrag `i` der `root_table` zurück. Liegt `i` außerhalb der `root_table`, wird
+    /// `LevelView::Empty` zurückgegeben.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - Erster Teil von `split_integer_down` eines Elements.
+    #[inline]
+    pub fn root(&self, i: usize) -> LevelView<'_, L3Ebene<T>, T> {
+        self.root_table
+            .get(i)
+            .map_or(LevelView::Empty, |pointer| pointer.view())
+    }
+
+    /// Gibt einen Iterator über alle gespeicherten Elemente in aufsteigender Reihenfolge zurück.
+    #[inline]
+    pub fn iter(&self) -> Range<'_, T> {
+        Range::new(&self.element_list)
+    }
+
+    /// Gibt einen Iterator über alle gespeicherten Elemente zurück, die in `range` liegen. Leere oder umgekehrte
+    /// Bereiche (Anfang > Ende) liefern einen leeren Iterator.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Bereich, dessen Grenzen mittels `locate_or_succ` und `locate_or_pred` in Indizes der Elementliste übersetzt werden.
+    #[inline]
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Range<'_, T> {
+        let (start, end) = self.range_indices(range);
+        Range::new(&self.element_list[start..end])
+    }
+
+    /// Gibt die Anzahl der gespeicherten Elemente zurück, die in `range` liegen.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Bereich, dessen Elemente gezählt werden sollen.
+    #[inline]
+    pub fn range_count<R: RangeBounds<T>>(&self, range: R) -> usize {
+        let (start, end) = self.range_indices(range);
+        end - start
+    }
+
+    /// Prüft, ob `element` gespeichert ist. Die Anfrage wird über `root_top` und die `lx_top`-Arrays der L2- und
+    /// L3-Ebene beantwortet und bricht beim ersten nicht gesetzten Bit ab. Auf die Elementliste wird nur zugegriffen,
+    /// wenn eine Ebene genau ein Element enthält.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, der gesucht wird.
+    #[inline]
+    pub fn contains(&self, element: T) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let (i, j, k) = Splittable::split_integer_down(&element);
+        if !self.root_top.is_set(i) {
+            return false;
+        }
+
+        match self.root_table[i].get() {
+            PointerEnum::First(second_level) => match second_level.try_get(j) {
+                Some(third_level) => match third_level.get() {
+                    PointerEnum::First(l) => l.lx_top.is_set(k as usize),
+                    PointerEnum::Second(e) => self.element_list[*e] == element,
+                },
+                None => false,
+            },
+            PointerEnum::Second(e) => self.element_list[*e] == element,
+        }
+    }
+
+    /// Gibt einen Cursor zurück, der auf dem kleinsten Element steht, das größer oder gleich `element` ist.
+    /// Existiert keines, steht der Cursor auf der Geisterposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, dessen Nachfolger (oder er selbst) angesteuert werden soll.
+    #[inline]
+    pub fn seek(&self, element: T) -> Cursor<'_, T> {
+        Cursor::new(self, self.locate_or_succ(element).unwrap_or(self.len()))
+    }
+
+    /// Gibt einen Cursor zurück, der auf dem größten Element steht, das kleiner oder gleich `element` ist.
+    /// Existiert keines, steht der Cursor auf der Geisterposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, dessen Vorgänger (oder er selbst) angesteuert werden soll.
+    #[inline]
+    pub fn seek_back(&self, element: T) -> Cursor<'_, T> {
+        Cursor::new(self, self.locate_or_pred(element).unwrap_or(self.len()))
+    }
+
+    /// Gibt die Anzahl der gespeicherten Elemente zurück, die kleiner oder gleich `element` sind.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Beliebiger Wert, dessen Rang bestimmt werden soll.
+    #[inline]
+    pub fn rank(&self, element: T) -> usize {
+        self.locate_or_pred(element).map_or(0, |index| index + 1)
+    }
+
+    /// Gibt das Element mit dem Index `index` (0-basiert, in sortierter Reihenfolge) zurück. Ist `index` zu groß, wird None zurückgegeben.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position des gesuchten Elements in der Elementliste.
+    #[inline]
+    pub fn select(&self, index: usize) -> Option<T> {
+        self.element_list.get(index).copied()
+    }
+
+    /// Gibt den Index von `element` in der Elementliste zurück, falls es gespeichert ist. Anderenfalls wird None zurückgegeben.
+    /// Damit bildet der STree die gespeicherten Werte monoton auf die Indizes 0..len() ab.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, dessen Index gesucht wird.
+    #[inline]
+    pub fn index_of(&self, element: T) -> Option<usize> {
+        self.locate_or_pred(element)
+            .filter(|&index| self.element_list[index] == element)
+    }
+
+    /// Übersetzt `range` in einen halboffenen Indexbereich [start, end) der Elementliste. Dabei gilt immer start <= end.
+    #[inline]
+    pub(crate) fn range_indices<R: RangeBounds<T>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&x) => self.locate_or_succ(x).unwrap_or(self.len()),
+            Bound::Excluded(&x) => match self.locate_or_succ(x) {
+                Some(index) if self.element_list[index] == x => index + 1,
+                Some(index) => index,
+                None => self.len(),
+            },
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&x) => self.locate_or_pred(x).map_or(0, |index| index + 1),
+            Bound::Excluded(&x) => match self.locate_or_pred(x) {
+                Some(index) if self.element_list[index] == x => index,
+                Some(index) => index + 1,
+                None => 0,
+            },
+            Bound::Unbounded => self.len(),
+        };
+
+        (start, end.max(start))
+    }
+
     /// Gibt das Maximum der übergebenen Ebene zurück.
     ///
     /// # Arguments
@@ -425,7 +636,7 @@ impl<T: Int> STree<T> {
     #[inline]
     pub fn locate_or_pred(&self, element: T) -> Option<usize> {
         // Paper z.1
-        if element < self.minimum().unwrap() {
+        if self.is_empty() || element < self.element_list[0] {
             return None;
         }
 
@@ -442,36 +653,7 @@ impl<T: Int> STree<T> {
 
         // Paper z. 4 (durch die Match-Arme)
         match self.root_table[i].get() {
-            PointerEnum::First(l) => {
-                let second_level = l;
-                let third_level = second_level.try_get(j);
-                // Paper z. 6 mit kleiner Anpassung wegen "Perfekten-Hashings"
-                if third_level.is_none()
-                    || element < self.element_list[third_level.unwrap().minimum()]
-                {
-                    let new_j = second_level.lx_top.get_prev_set_bit(j as usize);
-                    return new_j
-                        .and_then(|x| second_level.try_get(x as LXKey))
-                        .map(|x| x.maximum());
-                }
-
-                // Paper z.7
-                match third_level.unwrap().get() {
-                    PointerEnum::First(l) => {
-                        if l.lx_top.is_set(k as usize) {
-                            return Some(*l.get(k));
-                        } else {
-                            // Paper z.8
-                            let new_k = (*l).lx_top.get_prev_set_bit(k as usize);
-                            return new_k.map(|x| *(*l).try_get(x as LXKey).unwrap());
-                        }
-                    }
-                    // Paper z.7
-                    PointerEnum::Second(e) => {
-                        return Some(*e);
-                    }
-                }
-            }
+            PointerEnum::First(l) => self.locate_or_pred_l2(l, element, j, k),
 
             PointerEnum::Second(e) => {
                 return Some(*e);
@@ -489,7 +671,7 @@ impl<T: Int> STree<T> {
     #[inline]
     pub fn locate_or_succ(&self, element: T) -> Option<usize> {
         // Paper z.1
-        if element > self.maximum().unwrap() {
+        if self.is_empty() || element > self.element_list[self.len() - 1] {
             return None;
         }
 
@@ -506,37 +688,138 @@ impl<T: Int> STree<T> {
 
         // Paper z. 4 (durch die Match-Arme)
         match self.root_table[i].get() {
-            PointerEnum::First(l) => {
-                let second_level = l;
-                let third_level = second_level.try_get(j);
-                // Paper z. 6 mit kleiner Anpassung wegen "Perfekten-Hashings"
-                if third_level.is_none()
-                    || self.element_list[third_level.unwrap().maximum()] < element
-                {
-                    let new_j = second_level.lx_top.get_next_set_bit(j as usize);
-                    return new_j
-                        .and_then(|x| second_level.try_get(x as LXKey))
-                        .map(|x| x.minimum());
-                }
+            PointerEnum::First(l) => self.locate_or_succ_l2(l, element, j, k),
+
+            PointerEnum::Second(e) => {
+                return Some(*e);
+            }
+        }
+    }
 
-                // Paper z.7
-                match third_level.unwrap().get() {
-                    PointerEnum::First(l) => {
-                        if l.lx_top.is_set(k as usize) {
-                            return Some(*l.get(k));
-                        } else {
-                            // Paper z.8
-                            let new_k = (*l).lx_top.get_next_set_bit(k as usize);
-                            return new_k.map(|x| *(*l).try_get(x as LXKey).unwrap());
-                        };
-                    }
-                    // Paper z.7
-                    PointerEnum::Second(e) => {
-                        return Some(*e);
-                    }
+    /// Führt `locate_or_pred` ab der L2-Ebene `second_level` aus, die zu `element` gehört (Paper z.5 bis z.8).
+    /// Dabei muss `element` größer oder gleich dem Minimum von `second_level` sein.
+    ///
+    /// # Arguments
+    ///
+    /// * `second_level` - L2-Ebene aus `root_table`, in die `element` fällt.
+    /// * `element` - Wert, dessen Index bzw. Vorgänger-Index gesucht wird.
+    /// * `j` - Zweiter Teil von `split_integer_down(element)`.
+    /// * `k` - Dritter Teil von `split_integer_down(element)`.
+    #[inline]
+    pub(crate) fn locate_or_pred_l2(
+        &self,
+        second_level: &Level<L3Ebene<T>, T>,
+        element: T,
+        j: LXKey,
+        k: LXKey,
+    ) -> Option<usize> {
+        self.locate_or_pred_l3(second_level, second_level.try_get(j), element, j, k)
+    }
+
+    /// Wie `locate_or_pred_l2`, aber die L3-Ebene `third_level = second_level.try_get(j)` wurde bereits ermittelt.
+    /// Dadurch muss die perfekte Hashfunktion der L2-Ebene nicht erneut ausgewertet werden.
+    ///
+    /// # Arguments
+    ///
+    /// * `second_level` - L2-Ebene aus `root_table`, in die `element` fällt.
+    /// * `third_level` - Ergebnis von `second_level.try_get(j)`.
+    /// * `element` - Wert, dessen Index bzw. Vorgänger-Index gesucht wird.
+    /// * `j` - Zweiter Teil von `split_integer_down(element)`.
+    /// * `k` - Dritter Teil von `split_integer_down(element)`.
+    #[inline]
+    pub(crate) fn locate_or_pred_l3(
+        &self,
+        second_level: &Level<L3Ebene<T>, T>,
+        third_level: Option<&L3Ebene<T>>,
+        element: T,
+        j: LXKey,
+        k: LXKey,
+    ) -> Option<usize> {
+        // Paper z. 6 mit kleiner Anpassung wegen "Perfekten-Hashings"
+        if third_level.is_none() || element < self.element_list[third_level.unwrap().minimum()] {
+            let new_j = second_level.lx_top.get_prev_set_bit(j as usize);
+            return new_j
+                .and_then(|x| second_level.try_get(x as LXKey))
+                .map(|x| x.maximum());
+        }
+
+        // Paper z.7
+        match third_level.unwrap().get() {
+            PointerEnum::First(l) => {
+                if l.lx_top.is_set(k as usize) {
+                    return Some(*l.get(k));
+                } else {
+                    // Paper z.8
+                    let new_k = (*l).lx_top.get_prev_set_bit(k as usize);
+                    return new_k.map(|x| *(*l).try_get(x as LXKey).unwrap());
                 }
             }
+            // Paper z.7
+            PointerEnum::Second(e) => {
+                return Some(*e);
+            }
+        }
+    }
 
+    /// Führt `locate_or_succ` ab der L2-Ebene `second_level` aus, die zu `element` gehört (Paper z.5 bis z.8).
+    /// Dabei muss `element` kleiner oder gleich dem Maximum von `second_level` sein.
+    ///
+    /// # Arguments
+    ///
+    /// * `second_level` - L2-Ebene aus `root_table`, in die `element` fällt.
+    /// * `element` - Wert, dessen Index bzw. Nachfolger-Index gesucht wird.
+    /// * `j` - Zweiter Teil von `split_integer_down(element)`.
+    /// * `k` - Dritter Teil von `split_integer_down(element)`.
+    #[inline]
+    pub(crate) fn locate_or_succ_l2(
+        &self,
+        second_level: &Level<L3Ebene<T>, T>,
+        element: T,
+        j: LXKey,
+        k: LXKey,
+    ) -> Option<usize> {
+        self.locate_or_succ_l3(second_level, second_level.try_get(j), element, j, k)
+    }
+
+    /// Wie `locate_or_succ_l2`, aber die L3-Ebene `third_level = second_level.try_get(j)` wurde bereits ermittelt.
+    /// Dadurch muss die perfekte Hashfunktion der L2-Ebene nicht erneut ausgewertet werden.
+    ///
+    /// # Arguments
+    ///
+    /// * `second_level` - L2-Ebene aus `root_table`, in die `element` fällt.
+    /// * `third_level` - Ergebnis von `second_level.try_get(j)`.
+    /// * `element` - Wert, dessen Index bzw. Nachfolger-Index gesucht wird.
+    /// * `j` - Zweiter Teil von `split_integer_down(element)`.
+    /// * `k` - Dritter Teil von `split_integer_down(element)`.
+    #[inline]
+    pub(crate) fn locate_or_succ_l3(
+        &self,
+        second_level: &Level<L3Ebene<T>, T>,
+        third_level: Option<&L3Ebene<T>>,
+        element: T,
+        j: LXKey,
+        k: LXKey,
+    ) -> Option<usize> {
+        // Paper z. 6 mit kleiner Anpassung wegen "Perfekten-Hashings"
+        if third_level.is_none() || self.element_list[third_level.unwrap().maximum()] < element {
+            let new_j = second_level.lx_top.get_next_set_bit(j as usize);
+            return new_j
+                .and_then(|x| second_level.try_get(x as LXKey))
+                .map(|x| x.minimum());
+        }
+
+        // Paper z.7
+        match third_level.unwrap().get() {
+            PointerEnum::First(l) => {
+                if l.lx_top.is_set(k as usize) {
+                    return Some(*l.get(k));
+                } else {
+                    // Paper z.8
+                    let new_k = (*l).lx_top.get_next_set_bit(k as usize);
+                    return new_k.map(|x| *(*l).try_get(x as LXKey).unwrap());
+                };
+            }
+            // Paper z.7
             PointerEnum::Second(e) => {
                 return Some(*e);
             }
@@ -544,6 +827,69 @@ impl<T: Int> STree<T> {
     }
 }
 
+impl<T: Int> Default for STree<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: Int> std::iter::FromIterator<T> for STree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+impl<T: Int> StaticPredecessorSet<T> for STree<T> {
+    /// Gibt das größte Element zurück, das echt kleiner als `number` ist.
+    #[inline]
+    fn predecessor(&self, number: T) -> Option<T> {
+        let index = self.locate_or_pred(number)?;
+        if self.element_list[index] < number {
+            Some(self.element_list[index])
+        } else if index > 0 {
+            Some(self.element_list[index - 1])
+        } else {
+            None
+        }
+    }
+
+    /// Gibt das kleinste Element zurück, das echt größer als `number` ist.
+    #[inline]
+    fn successor(&self, number: T) -> Option<T> {
+        let index = self.locate_or_succ(number)?;
+        if self.element_list[index] > number {
+            Some(self.element_list[index])
+        } else {
+            self.element_list.get(index + 1).copied()
+        }
+    }
+
+    #[inline]
+    fn predecessor_or_equal(&self, number: T) -> Option<T> {
+        self.locate_or_pred(number).map(|x| self.element_list[x])
+    }
+
+    #[inline]
+    fn successor_or_equal(&self, number: T) -> Option<T> {
+        self.locate_or_succ(number).map(|x| self.element_list[x])
+    }
+
+    #[inline]
+    fn minimum(&self) -> Option<T> {
+        STree::minimum(self)
+    }
+
+    #[inline]
+    fn maximum(&self) -> Option<T> {
+        STree::maximum(self)
+    }
+
+    #[inline]
+    fn contains(&self, number: T) -> bool {
+        STree::contains(self, number)
+    }
+}
+
 /// Zwischenschicht zwischen dem Root-Array und des Element-Arrays.
 #[derive(Clone)]
 #[repr(align(4))]
@@ -601,6 +947,12 @@ impl<T, E> Level<T, E> {
         }
     }
 
+    /// Gibt die LX-Top-Tabelle zurück, in der alle belegten Schlüssel dieser Ebene markiert sind.
+    #[inline]
+    pub(crate) fn lx_top(&self) -> &TopArray<E, u8> {
+        &self.lx_top
+    }
+
     /// Der zum `key` gehörende gehashte Wert wird aus der Datenstruktur ermittelt. Hierbei muss sichergestellt sein
     /// das zu `key` ein Schlüssel gehört. Anderenfalls sollte `try_hash` verwendet werden
     ///
@@ -608,7 +960,17 @@ impl<T, E> Level<T, E> {
     ///
     /// * `key` - u8-Wert mit dessen Hilfe das zu `key` gehörende Objekt aus dem Array `objects` bestimmt werden kann.
     #[inline]
-    pub fn get(&mut self, key: LXKey) -> &mut T {
+    pub fn get(&self, key: LXKey) -> &T {
+        self.hash_map.get(&key)
+    }
+
+    /// Wie `get`, gibt aber eine schreibende Referenz zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - u8-Wert mit dessen Hilfe das zu `key` gehörende Objekt aus dem Array `objects` bestimmt werden kann.
+    #[inline]
+    pub fn get_mut(&mut self, key: LXKey) -> &mut T {
         self.hash_map.get_mut(&key)
     }
 }
@@ -0,0 +1,128 @@
+use crate::default::build::BuildError;
+use crate::default::immutable::Int;
+use crate::default::map::STreeMap;
+use std::ops::Range;
+
+/// Fehler, die beim Bauen einer `STreeIntervalMap` erkannt werden. `index` gibt jeweils die Position des fehlerhaften
+/// Intervalls in der übergebenen Liste an.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalError {
+    /// Das Intervall an Position `index` ist leer, d.h. sein Anfang ist nicht kleiner als sein Ende.
+    EmptyInterval { index: usize },
+
+    /// Das Intervall an Position `index` überschneidet sich mit einem anderen Intervall der Eingabe.
+    Overlapping { index: usize },
+
+    /// Fehler beim Bauen der zugrunde liegenden `STreeMap`.
+    Build(BuildError),
+}
+
+impl From<BuildError> for IntervalError {
+    fn from(error: BuildError) -> Self {
+        IntervalError::Build(error)
+    }
+}
+
+impl std::fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            IntervalError::EmptyInterval { index } => {
+                write!(f, "Intervall an Position {} ist leer", index)
+            }
+            IntervalError::Overlapping { index } => write!(
+                f,
+                "Intervall an Position {} überschneidet sich mit einem anderen Intervall",
+                index
+            ),
+            IntervalError::Build(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IntervalError::Build(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Ordnet disjunkten, halboffenen Intervallen [start, end) jeweils einen Wert zu. Die Anfänge der Intervalle werden in
+/// einer `STreeMap` abgelegt, sodass das Intervall, das einen Wert x enthält, mit einer Vorgängersuche nach x und einem
+/// Vergleich mit dem Ende des gefundenen Intervalls bestimmt wird.
+#[derive(Clone)]
+pub struct STreeIntervalMap<T, V> {
+    /// Bildet den Anfang jedes Intervalls auf dessen (exklusives) Ende und den zugehörigen Wert ab.
+    intervals: STreeMap<T, (T, V)>,
+}
+
+impl<T: Int, V> STreeIntervalMap<T, V> {
+    /// Gibt eine STreeIntervalMap mit den in `intervals` enthaltenen Intervallen zurück. Die Intervalle dürfen in
+    /// beliebiger Reihenfolge übergeben werden. Leere und sich überschneidende Intervalle werden mit einem `IntervalError`
+    /// abgelehnt, dessen Position sich auf `intervals` bezieht.
+    ///
+    /// # Arguments
+    ///
+    /// * `intervals` - Liste von Tripeln (start, end, value), die jeweils das Intervall [start, end) beschreiben.
+    pub fn try_new(intervals: Vec<(T, T, V)>) -> Result<Self, IntervalError> {
+        if let Some(index) = intervals.iter().position(|&(start, end, _)| start >= end) {
+            return Err(IntervalError::EmptyInterval { index });
+        }
+
+        let mut intervals: Vec<(usize, (T, T, V))> = intervals.into_iter().enumerate().collect();
+        intervals.sort_by_key(|&(_, (start, _, _))| start);
+
+        for n in 1..intervals.len() {
+            let (index, (start, _, _)) = intervals[n];
+            if (intervals[n - 1].1).1 > start {
+                return Err(IntervalError::Overlapping { index });
+            }
+        }
+
+        // Nach den obigen Prüfungen sind die Anfänge aufsteigend sortiert und eindeutig.
+        let entries = intervals
+            .into_iter()
+            .map(|(_, (start, end, value))| (start, (end, value)))
+            .collect();
+        Ok(Self {
+            intervals: STreeMap::new(entries),
+        })
+    }
+
+    /// Gibt die Anzahl der gespeicherten Intervalle zurück.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Gibt zurück, ob keine Intervalle gespeichert sind.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Gibt das Intervall, das `element` enthält, zusammen mit seinem Wert zurück. Liegt `element` in keinem
+    /// Intervall, wird None zurückgegeben.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, dessen Intervall gesucht wird.
+    #[inline]
+    pub fn get(&self, element: T) -> Option<(Range<T>, &V)> {
+        let (start, (end, value)) = self.intervals.predecessor_entry(element)?;
+        if element < *end {
+            Some((start..*end, value))
+        } else {
+            None
+        }
+    }
+
+    /// Gibt einen Iterator über alle Intervalle und ihre Werte in aufsteigender Reihenfolge zurück.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Range<T>, &V)> + '_ {
+        self.intervals
+            .iter()
+            .map(|(start, (end, value))| (start..*end, value))
+    }
+}
@@ -0,0 +1,100 @@
+/// Iterator über einen zusammenhängenden Teil der Elementliste eines STrees. Er wird von `STree::range` und
+/// `STree::iter` erzeugt und liefert die Elemente in aufsteigender (bzw. mit `rev()` absteigender) Reihenfolge.
+#[derive(Clone, Debug)]
+pub struct Range<'a, T> {
+    inner: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Range<'a, T> {
+    /// Gibt einen Iterator über alle Elemente aus `elements` zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Ausschnitt der sortierten Elementliste.
+    #[inline]
+    pub(crate) fn new(elements: &'a [T]) -> Self {
+        Self {
+            inner: elements.iter(),
+        }
+    }
+
+    /// Gibt die noch nicht zurückgegebenen Elemente als Slice zurück.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.inner.as_slice()
+    }
+}
+
+impl<'a, T: Copy> Iterator for Range<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.inner.next().copied()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.inner.nth(n).copied()
+    }
+}
+
+impl<'a, T: Copy> DoubleEndedIterator for Range<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back().copied()
+    }
+}
+
+impl<'a, T: Copy> ExactSizeIterator for Range<'a, T> {}
+
+impl<'a, T: Copy> std::iter::FusedIterator for Range<'a, T> {}
+
+/// Iterator über Schlüssel-Wert-Paare einer `STreeMap`. Er wird von `STreeMap::range` und `STreeMap::iter` erzeugt.
+#[derive(Clone, Debug)]
+pub struct MapRange<'a, K, V> {
+    keys: std::slice::Iter<'a, K>,
+    values: std::slice::Iter<'a, V>,
+}
+
+impl<'a, K, V> MapRange<'a, K, V> {
+    /// Gibt einen Iterator über die Paare (`keys[i]`, `values[i]`) zurück. Beide Slices müssen gleich lang sein.
+    #[inline]
+    pub(crate) fn new(keys: &'a [K], values: &'a [V]) -> Self {
+        debug_assert_eq!(keys.len(), values.len());
+        Self {
+            keys: keys.iter(),
+            values: values.iter(),
+        }
+    }
+}
+
+impl<'a, K: Copy, V> Iterator for MapRange<'a, K, V> {
+    type Item = (K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, &'a V)> {
+        Some((*self.keys.next()?, self.values.next()?))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<'a, K: Copy, V> DoubleEndedIterator for MapRange<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(K, &'a V)> {
+        Some((*self.keys.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<'a, K: Copy, V> ExactSizeIterator for MapRange<'a, K, V> {}
+
+impl<'a, K: Copy, V> std::iter::FusedIterator for MapRange<'a, K, V> {}
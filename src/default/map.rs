@@ -0,0 +1,156 @@
+use crate::default::build::{self, BuildError};
+use crate::default::immutable::{Int, STree};
+use crate::default::iter::MapRange;
+use std::ops::RangeBounds;
+
+/// Statische Predecessor-Datenstruktur, die zu jedem Schlüssel einen Wert speichert. Die Schlüssel werden in einem
+/// `STree` abgelegt, die Werte in einem Array, das parallel zur Elementliste des STrees sortiert ist. Jede Anfrage
+/// liefert somit einen Index, über den der zugehörige Wert ohne weitere Suche gelesen werden kann.
+#[derive(Clone)]
+pub struct STreeMap<K, V> {
+    /// Enthält alle Schlüssel in sortierter Reihenfolge.
+    keys: STree<K>,
+
+    /// `values[i]` gehört zum Schlüssel `keys.element_list[i]`.
+    values: Box<[V]>,
+}
+
+impl<K: Int, V> STreeMap<K, V> {
+    /// Gibt eine STreeMap mit den in `entries` enthaltenen Schlüssel-Wert-Paaren zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - Eine nach Schlüsseln sortierte Liste von Paaren. Kein Schlüssel darf doppelt vorkommen!
+    pub fn new(entries: Vec<(K, V)>) -> Self {
+        let (keys, values): (Vec<K>, Vec<V>) = entries.into_iter().unzip();
+        Self {
+            keys: STree::new(keys.into_boxed_slice()),
+            values: values.into_boxed_slice(),
+        }
+    }
+
+    /// Wie `new`, allerdings werden die Schlüssel vorher geprüft. Die Position im Fehler bezieht sich auf `entries`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - Eine nach Schlüsseln sortierte Liste von Paaren.
+    pub fn try_new(entries: Vec<(K, V)>) -> Result<Self, BuildError> {
+        let (keys, values): (Vec<K>, Vec<V>) = entries.into_iter().unzip();
+        build::validate(&keys)?;
+        Ok(Self {
+            keys: STree::new(keys.into_boxed_slice()),
+            values: values.into_boxed_slice(),
+        })
+    }
+
+    /// Gibt eine leere STreeMap zurück.
+    #[inline]
+    pub fn empty() -> Self {
+        Self {
+            keys: STree::empty(),
+            values: vec![].into_boxed_slice(),
+        }
+    }
+
+    /// Gibt die Anzahl der gespeicherten Paare zurück.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Gibt zurück, ob die STreeMap leer ist.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Gibt den STree mit allen Schlüsseln zurück. Die Indizes seiner Anfragen sind zugleich Indizes der Werte.
+    #[inline]
+    pub fn keys(&self) -> &STree<K> {
+        &self.keys
+    }
+
+    /// Gibt den zu `key` gehörenden Wert zurück, falls `key` gespeichert ist.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Schlüssel, dessen Wert gesucht wird.
+    #[inline]
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.keys.index_of(key).map(|index| &self.values[index])
+    }
+
+    /// Gibt den zu `key` gehörenden Wert veränderbar zurück, falls `key` gespeichert ist.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Schlüssel, dessen Wert gesucht wird.
+    #[inline]
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        let index = self.keys.index_of(key)?;
+        Some(&mut self.values[index])
+    }
+
+    /// Gibt den größten Schlüssel, der kleiner oder gleich `key` ist, zusammen mit seinem Wert zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Beliebiger Wert, dessen Vorgänger (oder er selbst) gesucht wird.
+    #[inline]
+    pub fn predecessor_entry(&self, key: K) -> Option<(K, &V)> {
+        self.keys.locate_or_pred(key).map(|index| self.entry(index))
+    }
+
+    /// Gibt den kleinsten Schlüssel, der größer oder gleich `key` ist, zusammen mit seinem Wert zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Beliebiger Wert, dessen Nachfolger (oder er selbst) gesucht wird.
+    #[inline]
+    pub fn successor_entry(&self, key: K) -> Option<(K, &V)> {
+        self.keys.locate_or_succ(key).map(|index| self.entry(index))
+    }
+
+    /// Gibt einen Iterator über alle Paare zurück, deren Schlüssel in `range` liegen.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Bereich der Schlüssel.
+    #[inline]
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> MapRange<'_, K, V> {
+        let (start, end) = self.keys.range_indices(range);
+        MapRange::new(
+            &self.keys.element_list[start..end],
+            &self.values[start..end],
+        )
+    }
+
+    /// Gibt einen Iterator über alle Paare in aufsteigender Reihenfolge der Schlüssel zurück.
+    #[inline]
+    pub fn iter(&self) -> MapRange<'_, K, V> {
+        MapRange::new(&self.keys.element_list, &self.values)
+    }
+
+    /// Gibt einen Iterator über alle Werte in aufsteigender Reihenfolge der Schlüssel zurück.
+    #[inline]
+    pub fn values(&self) -> std::slice::Iter<'_, V> {
+        self.values.iter()
+    }
+
+    /// Gibt einen Iterator über alle Werte zurück, mit dem diese verändert werden können. Die Schlüssel bleiben unverändert.
+    #[inline]
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, V> {
+        self.values.iter_mut()
+    }
+
+    #[inline]
+    fn entry(&self, index: usize) -> (K, &V) {
+        (self.keys.element_list[index], &self.values[index])
+    }
+}
+
+impl<K: Int, V> Default for STreeMap<K, V> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -1,5 +1,17 @@
 pub mod immutable;
 //pub mod mutable;
+mod batch;
+pub mod buckets;
 pub mod build;
+pub mod cursor;
+pub mod gaps;
+pub mod interval;
+pub mod iter;
+pub mod map;
+pub mod multiset;
+pub mod nearest;
+#[cfg(feature = "rayon")]
+mod parallel;
+pub mod set_ops;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,144 @@
+use crate::default::build::{self, BuildError};
+use crate::default::immutable::{Int, STree};
+
+/// Statische Multimenge. Jeder Wert wird genau einmal in einem `STree` abgelegt. Zusätzlich wird für jeden Wert der
+/// Bereich seiner Vorkommen in der sortierten Multimenge gespeichert, sodass Anzahl und Rang mit einer einzigen
+/// Vorgängersuche bestimmt werden können.
+#[derive(Clone)]
+pub struct STreeMultiset<T> {
+    /// Enthält jeden gespeicherten Wert genau einmal.
+    keys: STree<T>,
+
+    /// `offsets[i]..offsets[i + 1]` sind die Positionen der Vorkommen von `keys.element_list[i]` in der sortierten
+    /// Multimenge. Es gilt `offsets.len() == keys.len() + 1`.
+    offsets: Box<[usize]>,
+}
+
+impl<T: Int> STreeMultiset<T> {
+    /// Gibt eine Multimenge mit den in `elements` enthaltenen Werten zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Eine Liste mit sortierten Werten. Werte dürfen mehrfach vorkommen.
+    pub fn new(elements: Box<[T]>) -> Self {
+        let mut keys = Vec::new();
+        let mut offsets = vec![0];
+        for (index, &element) in elements.iter().enumerate() {
+            if index == 0 || elements[index - 1] != element {
+                if index > 0 {
+                    offsets.push(index);
+                }
+                keys.push(element);
+            }
+        }
+        if !elements.is_empty() {
+            offsets.push(elements.len());
+        }
+
+        Self {
+            keys: STree::new(keys.into_boxed_slice()),
+            offsets: offsets.into_boxed_slice(),
+        }
+    }
+
+    /// Wie `new`, allerdings wird die Eingabe vorher auf Sortierung geprüft.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Eine Liste mit sortierten Werten. Werte dürfen mehrfach vorkommen.
+    pub fn try_new(elements: Box<[T]>) -> Result<Self, BuildError> {
+        build::validate_multiset(&elements)?;
+        Ok(Self::new(elements))
+    }
+
+    /// Gibt eine Multimenge mit den in `elements` enthaltenen Werten zurück. Die Werte werden vorher mittels
+    /// Radixsort sortiert.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Eine beliebige Liste von Werten.
+    pub fn from_unsorted(mut elements: Vec<T>) -> Self {
+        build::radix_sort(&mut elements);
+        Self::new(elements.into_boxed_slice())
+    }
+
+    /// Gibt die Anzahl der gespeicherten Werte (mit Vielfachheit) zurück.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets[self.offsets.len() - 1]
+    }
+
+    /// Gibt zurück, ob die Multimenge leer ist.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Gibt den STree zurück, der jeden gespeicherten Wert genau einmal enthält.
+    #[inline]
+    pub fn keys(&self) -> &STree<T> {
+        &self.keys
+    }
+
+    /// Gibt zurück, wie oft `element` gespeichert ist.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, dessen Vielfachheit bestimmt werden soll.
+    #[inline]
+    pub fn count(&self, element: T) -> usize {
+        self.keys
+            .index_of(element)
+            .map_or(0, |index| self.offsets[index + 1] - self.offsets[index])
+    }
+
+    /// Gibt zurück, ob `element` mindestens einmal gespeichert ist.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Wert, der gesucht wird.
+    #[inline]
+    pub fn contains(&self, element: T) -> bool {
+        self.keys.contains(element)
+    }
+
+    /// Gibt die Anzahl der gespeicherten Werte (mit Vielfachheit) zurück, die kleiner oder gleich `element` sind.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Beliebiger Wert, dessen Rang bestimmt werden soll.
+    #[inline]
+    pub fn rank(&self, element: T) -> usize {
+        self.keys
+            .locate_or_pred(element)
+            .map_or(0, |index| self.offsets[index + 1])
+    }
+
+    /// Gibt den Wert an Position `index` der sortierten Multimenge (mit Vielfachheit) zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position in der sortierten Multimenge.
+    #[inline]
+    pub fn select(&self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        // Gesucht ist der letzte Lauf, der an oder vor `index` beginnt.
+        let run = match self.offsets.binary_search(&index) {
+            Ok(run) => run,
+            Err(run) => run - 1,
+        };
+        self.keys.select(run)
+    }
+
+    /// Gibt einen Iterator über alle gespeicherten Werte in aufsteigender Reihenfolge zurück. Mehrfach gespeicherte
+    /// Werte werden entsprechend oft geliefert.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + '_ {
+        self.keys
+            .iter()
+            .zip(self.offsets.windows(2))
+            .flat_map(|(element, run)| std::iter::repeat(element).take(run[1] - run[0]))
+    }
+}
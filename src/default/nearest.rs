@@ -0,0 +1,107 @@
+use crate::default::immutable::{Int, STree};
+
+/// Legt fest, welches Element bei gleichem Abstand zum Anfragewert bevorzugt wird.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Das kleinere der beiden Elemente wird bevorzugt.
+    Lower,
+    /// Das größere der beiden Elemente wird bevorzugt.
+    Upper,
+}
+
+impl<T: Int> STree<T> {
+    /// Gibt das gespeicherte Element mit dem kleinsten absoluten Abstand zu `element` samt seinem Index in der
+    /// Elementliste zurück. Ist der STree leer, wird None zurückgegeben.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Beliebiger Wert, zu dem das nächste Element gesucht wird.
+    /// * `tie_break` - Legt fest, ob bei gleichem Abstand der Vorgänger oder der Nachfolger zurückgegeben wird.
+    pub fn nearest(&self, element: T, tie_break: TieBreak) -> Option<(T, usize)> {
+        let upper = self.nearest_start(element);
+        let index = if self.nearest_side(element, upper, upper, tie_break)? {
+            upper - 1
+        } else {
+            upper
+        };
+        Some((self.element_list[index], index))
+    }
+
+    /// Gibt die (höchstens) `k` gespeicherten Elemente mit dem kleinsten absoluten Abstand zu `element` samt ihren
+    /// Indizes zurück. Die Elemente sind aufsteigend nach ihrem Abstand sortiert.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Beliebiger Wert, zu dem die nächsten Elemente gesucht werden.
+    /// * `k` - Maximale Anzahl zurückgegebener Elemente.
+    /// * `tie_break` - Legt fest, ob bei gleichem Abstand der Vorgänger oder der Nachfolger zuerst gewählt wird.
+    pub fn nearest_k(&self, element: T, k: usize, tie_break: TieBreak) -> Vec<(T, usize)> {
+        let k = k.min(self.len());
+        let mut result = Vec::with_capacity(k);
+
+        // Alle Indizes in [lower, upper) wurden bereits ausgegeben.
+        let mut upper = self.nearest_start(element);
+        let mut lower = upper;
+        while result.len() < k {
+            let index = match self.nearest_side(element, lower, upper, tie_break) {
+                Some(true) => {
+                    lower -= 1;
+                    lower
+                }
+                Some(false) => {
+                    upper += 1;
+                    upper - 1
+                }
+                None => break,
+            };
+            result.push((self.element_list[index], index));
+        }
+        result
+    }
+
+    /// Gibt den Index des kleinsten Elements >= `element` zurück bzw. `len()`, falls es keines gibt. Vorgänger und
+    /// Nachfolger liegen in der Elementliste direkt nebeneinander, sodass ein einziger Abstieg genügt.
+    #[inline]
+    fn nearest_start(&self, element: T) -> usize {
+        match self.locate_or_pred(element) {
+            Some(index) if self.element_list[index] == element => index,
+            Some(index) => index + 1,
+            None => 0,
+        }
+    }
+
+    /// Entscheidet, ob als Nächstes das Element bei `lower - 1` (true) oder bei `upper` (false) näher an `element` liegt.
+    /// Gibt None zurück, falls auf beiden Seiten keine Elemente mehr vorhanden sind.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Anfragewert.
+    /// * `lower` - Alle Indizes kleiner als `lower` sind noch Kandidaten.
+    /// * `upper` - Alle Indizes größer oder gleich `upper` sind noch Kandidaten.
+    /// * `tie_break` - Regel für gleiche Abstände.
+    #[inline]
+    fn nearest_side(
+        &self,
+        element: T,
+        lower: usize,
+        upper: usize,
+        tie_break: TieBreak,
+    ) -> Option<bool> {
+        match (lower > 0, upper < self.len()) {
+            (false, false) => None,
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            (true, true) => {
+                let value: u64 = element.into();
+                let lower_value: u64 = self.element_list[lower - 1].into();
+                let upper_value: u64 = self.element_list[upper].into();
+                let lower_distance = value - lower_value;
+                let upper_distance = upper_value - value;
+                Some(
+                    lower_distance < upper_distance
+                        || (lower_distance == upper_distance && tie_break == TieBreak::Lower),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::default::immutable::{Int, STree};
+use rayon::prelude::*;
+
+/// Anzahl der Anfragen, die ein Thread am Stück über `predecessors_batch` bzw. `successors_batch` abarbeitet.
+/// Sie sollte deutlich größer als `PIPELINE_WIDTH` sein, damit sich das Verteilen auf die Threads lohnt.
+const PARALLEL_CHUNK_SIZE: usize = 4096;
+
+impl<T: Int> STree<T> {
+    /// Führt `locate_or_pred` für alle Werte aus `elements` parallel aus und gibt die Ergebnisse in derselben
+    /// Reihenfolge zurück. Dazu wird `elements` in Blöcke aufgeteilt, die jeweils mit `predecessors_batch` abgearbeitet
+    /// werden.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Beliebige Liste von Anfragen.
+    pub fn par_predecessors(&self, elements: &[T]) -> Vec<Option<usize>> {
+        self.par_chunked(elements, Self::predecessors_batch)
+    }
+
+    /// Führt `locate_or_succ` für alle Werte aus `elements` parallel aus und gibt die Ergebnisse in derselben
+    /// Reihenfolge zurück. Die Blöcke werden jeweils mit `successors_batch` abgearbeitet.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Beliebige Liste von Anfragen.
+    pub fn par_successors(&self, elements: &[T]) -> Vec<Option<usize>> {
+        self.par_chunked(elements, Self::successors_batch)
+    }
+
+    /// Teilt `elements` in Blöcke der Größe `PARALLEL_CHUNK_SIZE` auf, arbeitet diese mit `batch` parallel ab und fügt
+    /// die Ergebnisse in der ursprünglichen Reihenfolge zusammen.
+    fn par_chunked<F: Fn(&Self, &[T]) -> Vec<Option<usize>> + Sync>(
+        &self,
+        elements: &[T],
+        batch: F,
+    ) -> Vec<Option<usize>> {
+        elements
+            .par_chunks(PARALLEL_CHUNK_SIZE)
+            .flat_map_iter(|chunk| batch(self, chunk))
+            .collect()
+    }
+}
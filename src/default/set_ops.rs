@@ -0,0 +1,407 @@
+use crate::default::immutable::{Int, STree};
+use std::cmp::Ordering;
+
+/// Ist eine Menge mehr als `GALLOP_RATIO`-mal so groß wie die andere, wird der Schnitt nicht mehr durch Mischen der
+/// Elementlisten, sondern durch Suchen der Elemente der kleineren Menge in der größeren Menge berechnet.
+const GALLOP_RATIO: usize = 32;
+
+impl<T: Int> STree<T> {
+    /// Gibt einen neuen STree mit allen Elementen zurück, die in `self` oder `other` enthalten sind.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Zweite Menge.
+    pub fn union(&self, other: &STree<T>) -> STree<T> {
+        STree::new(self.union_iter(other).collect())
+    }
+
+    /// Gibt einen neuen STree mit allen Elementen zurück, die in `self` und `other` enthalten sind.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Zweite Menge.
+    pub fn intersection(&self, other: &STree<T>) -> STree<T> {
+        STree::new(self.intersection_iter(other).collect())
+    }
+
+    /// Gibt einen neuen STree mit allen Elementen zurück, die in `self`, aber nicht in `other` enthalten sind.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Menge der Elemente, die entfernt werden.
+    pub fn difference(&self, other: &STree<T>) -> STree<T> {
+        STree::new(self.difference_iter(other).collect())
+    }
+
+    /// Gibt einen neuen STree mit allen Elementen zurück, die in genau einer der beiden Mengen enthalten sind.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Zweite Menge.
+    pub fn symmetric_difference(&self, other: &STree<T>) -> STree<T> {
+        STree::new(self.symmetric_difference_iter(other).collect())
+    }
+
+    /// Gibt einen Iterator über alle Elemente zurück, die in jedem der übergebenen STrees enthalten sind
+    /// (Leapfrog-Join). Die Elemente des kleinsten STrees dienen als Kandidaten. In allen anderen STrees wird mittels
+    /// `locate_or_succ` zum Kandidaten gesprungen. Liegt dort ein größeres Element, wird der kleinste STree bis zu
+    /// diesem Element vorgespult. Die Laufzeit hängt somit von der Größe des kleinsten STrees ab. Für eine leere
+    /// Liste wird ein leerer Iterator zurückgegeben.
+    ///
+    /// # Arguments
+    ///
+    /// * `trees` - Die zu schneidenden Mengen.
+    pub fn intersect_many<'a>(trees: &[&'a STree<T>]) -> IntersectMany<'a, T> {
+        let mut trees = trees.to_vec();
+        trees.sort_by_key(|tree| tree.len());
+        let done = trees.is_empty() || trees[0].is_empty();
+        IntersectMany {
+            positions: vec![0; trees.len()],
+            trees,
+            done,
+        }
+    }
+
+    /// Gibt einen Iterator über die Vereinigung von `self` und `other` in aufsteigender Reihenfolge zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Zweite Menge.
+    #[inline]
+    pub fn union_iter<'a>(&'a self, other: &'a STree<T>) -> Union<'a, T> {
+        Union {
+            a: &self.element_list,
+            b: &other.element_list,
+        }
+    }
+
+    /// Gibt einen Iterator über den Schnitt von `self` und `other` in aufsteigender Reihenfolge zurück. Ist eine der
+    /// beiden Mengen deutlich kleiner, werden ihre Elemente mittels `locate_or_succ` in der größeren Menge gesucht.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Zweite Menge.
+    #[inline]
+    pub fn intersection_iter<'a>(&'a self, other: &'a STree<T>) -> Intersection<'a, T> {
+        let (small, large) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        if small.len().saturating_mul(GALLOP_RATIO) < large.len() {
+            Intersection::Gallop {
+                small: &small.element_list,
+                large,
+            }
+        } else {
+            Intersection::Merge {
+                a: &self.element_list,
+                b: &other.element_list,
+            }
+        }
+    }
+
+    /// Gibt einen Iterator über alle Elemente aus `self`, die nicht in `other` enthalten sind, in aufsteigender
+    /// Reihenfolge zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Menge der Elemente, die übersprungen werden.
+    #[inline]
+    pub fn difference_iter<'a>(&'a self, other: &'a STree<T>) -> Difference<'a, T> {
+        Difference {
+            a: &self.element_list,
+            b: &other.element_list,
+        }
+    }
+
+    /// Gibt einen Iterator über alle Elemente, die in genau einer der beiden Mengen enthalten sind, in aufsteigender
+    /// Reihenfolge zurück.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Zweite Menge.
+    #[inline]
+    pub fn symmetric_difference_iter<'a>(
+        &'a self,
+        other: &'a STree<T>,
+    ) -> SymmetricDifference<'a, T> {
+        SymmetricDifference {
+            a: &self.element_list,
+            b: &other.element_list,
+        }
+    }
+}
+
+/// Lazy Vereinigung zweier STrees. Siehe `STree::union_iter`.
+#[derive(Clone)]
+pub struct Union<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+impl<'a, T: Int> Iterator for Union<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let ordering = match (self.a.first(), self.b.first()) {
+            (None, None) => return None,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(x), Some(y)) => x.cmp(y),
+        };
+        match ordering {
+            Ordering::Less => pop_front(&mut self.a),
+            Ordering::Greater => pop_front(&mut self.b),
+            Ordering::Equal => {
+                pop_front(&mut self.b);
+                pop_front(&mut self.a)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.a.len().max(self.b.len()),
+            Some(self.a.len() + self.b.len()),
+        )
+    }
+}
+
+impl<'a, T: Int> std::iter::FusedIterator for Union<'a, T> {}
+
+/// Lazy Schnitt zweier STrees. Siehe `STree::intersection_iter`.
+#[derive(Clone)]
+pub enum Intersection<'a, T> {
+    /// Beide Elementlisten werden gleichzeitig durchlaufen.
+    Merge { a: &'a [T], b: &'a [T] },
+
+    /// Die Elemente von `small` werden in `large` gesucht. Mit dem gefundenen Nachfolger werden alle Elemente aus
+    /// `small` übersprungen, die nicht im Schnitt liegen können. Die Suche in `small` startet dabei an der aktuellen
+    /// Position (siehe `gallop`).
+    Gallop { small: &'a [T], large: &'a STree<T> },
+}
+
+impl<'a, T: Int> Iterator for Intersection<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self {
+            Intersection::Merge { a, b } => loop {
+                let (x, y) = (*a.first()?, *b.first()?);
+                match x.cmp(&y) {
+                    Ordering::Less => *a = &a[1..],
+                    Ordering::Greater => *b = &b[1..],
+                    Ordering::Equal => {
+                        *a = &a[1..];
+                        *b = &b[1..];
+                        return Some(x);
+                    }
+                }
+            },
+            Intersection::Gallop { small, large } => loop {
+                let x = *small.first()?;
+                let y = match large.locate_or_succ(x) {
+                    Some(index) => large.element_list[index],
+                    None => {
+                        *small = &[];
+                        return None;
+                    }
+                };
+
+                if x == y {
+                    *small = &small[1..];
+                    return Some(x);
+                }
+                *small = &small[gallop(small, &y)..];
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Intersection::Merge { a, b } => (0, Some(a.len().min(b.len()))),
+            Intersection::Gallop { small, .. } => (0, Some(small.len())),
+        }
+    }
+}
+
+impl<'a, T: Int> std::iter::FusedIterator for Intersection<'a, T> {}
+
+/// Lazy Differenz zweier STrees. Siehe `STree::difference_iter`.
+#[derive(Clone)]
+pub struct Difference<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+impl<'a, T: Int> Iterator for Difference<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let x = *self.a.first()?;
+            let ordering = match self.b.first() {
+                Some(y) => x.cmp(y),
+                None => Ordering::Less,
+            };
+            match ordering {
+                Ordering::Less => return pop_front(&mut self.a),
+                Ordering::Greater => {
+                    pop_front(&mut self.b);
+                }
+                Ordering::Equal => {
+                    pop_front(&mut self.a);
+                    pop_front(&mut self.b);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.a.len().saturating_sub(self.b.len()),
+            Some(self.a.len()),
+        )
+    }
+}
+
+impl<'a, T: Int> std::iter::FusedIterator for Difference<'a, T> {}
+
+/// Lazy symmetrische Differenz zweier STrees. Siehe `STree::symmetric_difference_iter`.
+#[derive(Clone)]
+pub struct SymmetricDifference<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+impl<'a, T: Int> Iterator for SymmetricDifference<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let ordering = match (self.a.first(), self.b.first()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(x), Some(y)) => x.cmp(y),
+            };
+            match ordering {
+                Ordering::Less => return pop_front(&mut self.a),
+                Ordering::Greater => return pop_front(&mut self.b),
+                Ordering::Equal => {
+                    pop_front(&mut self.a);
+                    pop_front(&mut self.b);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.a.len() + self.b.len()))
+    }
+}
+
+impl<'a, T: Int> std::iter::FusedIterator for SymmetricDifference<'a, T> {}
+
+/// Lazy Schnitt beliebig vieler STrees. Siehe `STree::intersect_many`.
+#[derive(Clone)]
+pub struct IntersectMany<'a, T> {
+    /// Die zu schneidenden STrees, aufsteigend nach Größe sortiert.
+    trees: Vec<&'a STree<T>>,
+
+    /// Aktueller Index in der Elementliste des jeweiligen STrees. Alle kleineren Elemente wurden bereits abgearbeitet.
+    positions: Vec<usize>,
+
+    /// Wird gesetzt, sobald einer der STrees keine weiteren Elemente besitzt.
+    done: bool,
+}
+
+impl<'a, T: Int> IntersectMany<'a, T> {
+    /// Setzt die Position von `trees[t]` auf das kleinste Element >= `element` und gibt dieses zurück. Da die Kandidaten
+    /// aufsteigend sind, wird nur abgestiegen, wenn das Element an der aktuellen Position zu klein ist.
+    #[inline]
+    fn seek(&mut self, t: usize, element: T) -> Option<T> {
+        let tree = self.trees[t];
+        let position = self.positions[t];
+        if position >= tree.len() {
+            return None;
+        }
+        if tree.element_list[position] < element {
+            self.positions[t] = tree.locate_or_succ(element)?;
+        }
+        Some(tree.element_list[self.positions[t]])
+    }
+}
+
+impl<'a, T: Int> Iterator for IntersectMany<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+
+        let smallest = self.trees[0];
+        'candidates: while self.positions[0] < smallest.len() {
+            let candidate = smallest.element_list[self.positions[0]];
+            for t in 1..self.trees.len() {
+                match self.seek(t, candidate) {
+                    Some(element) if element == candidate => {}
+                    Some(element) => {
+                        // Der kleinste STree wird bis zum gefundenen Element vorgespult.
+                        match smallest.locate_or_succ(element) {
+                            Some(position) => self.positions[0] = position,
+                            None => break 'candidates,
+                        }
+                        continue 'candidates;
+                    }
+                    None => break 'candidates,
+                }
+            }
+
+            self.positions[0] += 1;
+            return Some(candidate);
+        }
+
+        self.done = true;
+        None
+    }
+}
+
+impl<'a, T: Int> std::iter::FusedIterator for IntersectMany<'a, T> {}
+
+/// Entfernt das erste Element aus `slice` und gibt es zurück.
+#[inline]
+fn pop_front<T: Copy>(slice: &mut &[T]) -> Option<T> {
+    let (&first, rest) = slice.split_first()?;
+    *slice = rest;
+    Some(first)
+}
+
+/// Gibt den Index des ersten Elements aus `slice` zurück, das nicht kleiner als `target` ist. Ausgehend vom Anfang von
+/// `slice` wird die Schrittweite verdoppelt, bis ein solches Element übersprungen wurde. Nur der letzte Schritt wird
+/// anschließend binär durchsucht, sodass die Laufzeit logarithmisch in der Sprungweite und nicht in `slice.len()` ist.
+///
+/// # Arguments
+///
+/// * `slice` - Aufsteigend sortierte Liste.
+/// * `target` - Gesuchter Wert.
+#[inline]
+fn gallop<T: Ord>(slice: &[T], target: &T) -> usize {
+    let mut low = 0;
+    let mut step = 1;
+    while low + step < slice.len() && slice[low + step] < *target {
+        low += step;
+        step *= 2;
+    }
+    let high = (low + step + 1).min(slice.len());
+    low + match slice[low..high].binary_search(target) {
+        Ok(index) | Err(index) => index,
+    }
+}
@@ -1,15 +1,27 @@
 use uint::{u40, u48};
 
-pub trait PredecessorSet<T> {
-    fn insert(&mut self, element: T);
-    fn delete(&mut self, element: T);
+/// Lesende Anfragen an eine Predecessor-Datenstruktur. Dieser Trait wird auch von statischen Datenstrukturen
+/// (z.B. `STree`) implementiert, die nach dem Bauen nicht mehr verändert werden können.
+pub trait StaticPredecessorSet<T> {
+    /// Gibt das größte gespeicherte Element zurück, das echt kleiner als `number` ist.
     fn predecessor(&self, number: T) -> Option<T>;
-    fn successor(&self, number: T) -> Option<T>; // Optional
+    /// Gibt das kleinste gespeicherte Element zurück, das echt größer als `number` ist.
+    fn successor(&self, number: T) -> Option<T>;
+    /// Gibt das größte gespeicherte Element zurück, das kleiner oder gleich `number` ist.
+    fn predecessor_or_equal(&self, number: T) -> Option<T>;
+    /// Gibt das kleinste gespeicherte Element zurück, das größer oder gleich `number` ist.
+    fn successor_or_equal(&self, number: T) -> Option<T>;
     fn minimum(&self) -> Option<T>;
     fn maximum(&self) -> Option<T>;
     fn contains(&self, number: T) -> bool;
 }
 
+/// Dynamische Predecessor-Datenstruktur, die zusätzlich zu den lesenden Anfragen das Einfügen und Löschen erlaubt.
+pub trait PredecessorSet<T>: StaticPredecessorSet<T> {
+    fn insert(&mut self, element: T);
+    fn delete(&mut self, element: T);
+}
+
 pub trait Splittable {
     fn split_integer_down(&self) -> (usize, u8, u8);
 }
@@ -62,27 +74,55 @@ impl Splittable for u64 {
     #[inline]
     fn split_integer_down(&self) -> (usize, u8, u8) {
         let i: usize = (*self >> 16) as usize;
-        // Die niedrigwertigsten 32 Bits element[32..63]
-        let low = *self & 0xFFFF;
-        // Bits 16 bis 32
-        let j: u8 = (low >> 16) as u8;
         // Die niedrigwertigsten 16 Bits element[0..15]
+        let low = *self & 0xFFFF;
+        // Bits 8 bis 15 element[8..15]
+        let j: u8 = (low >> 8) as u8;
+        // Die niedrigwertigsten 8 Bits element[0..7]
         let k: u8 = (*self & 0xFF) as u8;
         (i, j, k)
     }
 }
 
+/// Lädt die Cache-Zeile, in der `pointer` liegt, vorab in den L1-Cache. Auf anderen Architekturen als x86_64 passiert nichts.
+/// Es wird nicht auf `pointer` zugegriffen, sodass auch ungültige Adressen übergeben werden dürfen.
+#[inline(always)]
+pub fn prefetch<P>(pointer: *const P) {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+        _mm_prefetch::<_MM_HINT_T0>(pointer as *const i8);
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = pointer;
+}
+
+/// Lesender Zugriff auf das Objekt, auf das ein `Pointer` zeigt. Wird von `Pointer::get` zurückgegeben.
 pub enum PointerEnum<'a, T: 'a, E: 'a> {
+    First(&'a T),
+    Second(&'a E),
+}
+
+/// Schreibender Zugriff auf das Objekt, auf das ein `Pointer` zeigt. Wird von `Pointer::get_mut` zurückgegeben.
+pub enum PointerEnumMut<'a, T: 'a, E: 'a> {
     First(&'a mut T),
     Second(&'a mut E),
 }
 
-/// Dieser Struct beinhaltet einen RAW-Pointer, der entweder auf ein T oder ein E Objekt zeigt. Wichtig ist hierbei, dass T mit einem Vielfachen von 2 alligned werden muss!
+/// Dieser Struct beinhaltet einen RAW-Pointer, der entweder auf ein T oder ein E Objekt zeigt. Das niedrigste Bit des
+/// Zeigers ist bei einem E gesetzt. Daher müssen T und E mit einem Vielfachen von 2 alligned sein, was beim Übersetzen
+/// geprüft wird. Der Pointer besitzt das Objekt, auf das er zeigt, und verhält sich somit wie eine `Box<T>` bzw. `Box<E>`.
 pub struct Pointer<T, E> {
     pointer: *mut T,
-    phantom: std::marker::PhantomData<E>,
+    phantom: std::marker::PhantomData<(Box<T>, Box<E>)>,
 }
 
+// Der Pointer besitzt sein Objekt exklusiv und gibt Referenzen nur entsprechend der Ausleihe von `self` heraus.
+// Somit darf er wie eine Box zwischen Threads verschoben bzw. geteilt werden.
+unsafe impl<T: Send, E: Send> Send for Pointer<T, E> {}
+unsafe impl<T: Sync, E: Sync> Sync for Pointer<T, E> {}
+
 impl<T: Clone, E: Clone> Clone for Pointer<T, E> {
     fn clone(&self) -> Self {
         if self.pointer.is_null() {
@@ -102,54 +142,85 @@ impl<T, E> Drop for Pointer<T, E> {
             return;
         }
 
-        if (self.pointer as usize % 2) == 0 {
-            unsafe { Box::from_raw(self.pointer) };
-        } else {
-            debug_assert!((self.pointer as usize % 2) == 1);
-
-            unsafe { Box::from_raw((self.pointer as usize - 1) as *mut E) };
+        // Der Zeiger stammt aus `Box::into_raw` und wird nur an dieser Stelle wieder freigegeben.
+        unsafe {
+            if self.is_first() {
+                drop(Box::from_raw(self.pointer));
+            } else {
+                drop(Box::from_raw(self.second()));
+            }
         }
     }
 }
 
 impl<T, E> Pointer<T, E> {
+    /// Bricht das Übersetzen ab, falls T oder E nicht mit einem Vielfachen von 2 alligned sind. Dann wäre das
+    /// niedrigste Bit des Zeigers nicht frei für die Markierung.
+    const ALIGNED: () = assert!(
+        std::mem::align_of::<T>() >= 2 && std::mem::align_of::<E>() >= 2,
+        "Pointer<T, E> benötigt ein Alignment von mindestens 2 für T und E"
+    );
+
     pub fn from_first(b: Box<T>) -> Self {
-        let pointer = Box::into_raw(b);
-        debug_assert!(std::mem::align_of::<T>() % 2 == 0 && std::mem::align_of::<E>() % 2 == 0);
-        debug_assert!((pointer as usize % 2) == 0);
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ALIGNED;
 
         Self {
-            pointer: pointer,
+            pointer: Box::into_raw(b),
             phantom: std::marker::PhantomData,
         }
     }
 
     pub fn from_second(b: Box<E>) -> Self {
-        let pointer = Box::into_raw(b);
-        debug_assert!(std::mem::align_of::<T>() % 2 == 0 && std::mem::align_of::<E>() % 2 == 0);
-        debug_assert!((pointer as usize % 2) == 0);
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ALIGNED;
 
-        let pointer = (pointer as usize + 1) as *mut T;
+        let pointer = Box::into_raw(b).cast::<u8>().wrapping_add(1).cast::<T>();
         Self {
-            pointer: pointer,
+            pointer,
             phantom: std::marker::PhantomData,
         }
     }
 
-    pub fn get(&self) -> PointerEnum<T, E> {
+    /// Gibt eine lesende Referenz auf das Objekt zurück, auf das gezeigt wird.
+    pub fn get(&self) -> PointerEnum<'_, T, E> {
         if self.pointer.is_null() {
             panic!("Pointer<T> is null!");
         }
 
-        if (self.pointer as usize % 2) == 0 {
-            unsafe { PointerEnum::First(&mut (*self.pointer)) }
-        } else {
-            debug_assert!((self.pointer as usize % 2) == 1);
+        // Der Zeiger ist gültig, solange `self` lebt. Die Referenz ist an die Ausleihe von `self` gebunden.
+        unsafe {
+            if self.is_first() {
+                PointerEnum::First(&*self.pointer)
+            } else {
+                PointerEnum::Second(&*self.second())
+            }
+        }
+    }
 
-            unsafe { PointerEnum::Second(&mut *((self.pointer as usize - 1) as *mut E)) }
+    /// Gibt eine schreibende Referenz auf das Objekt zurück, auf das gezeigt wird.
+    pub fn get_mut(&mut self) -> PointerEnumMut<'_, T, E> {
+        if self.pointer.is_null() {
+            panic!("Pointer<T> is null!");
+        }
+
+        // Wie bei `get`, wegen `&mut self` gibt es keine weitere Referenz auf das Objekt.
+        unsafe {
+            if self.is_first() {
+                PointerEnumMut::First(&mut *self.pointer)
+            } else {
+                PointerEnumMut::Second(&mut *self.second())
+            }
         }
     }
 
+    /// Gibt die Adresse des Objekts zurück, auf das gezeigt wird (ohne Markierungsbit). Sie dient nur dem Prefetching
+    /// und darf nicht dereferenziert werden.
+    #[inline]
+    pub fn address(&self) -> *const u8 {
+        (self.pointer as usize & !1) as *const u8
+    }
+
     pub fn null() -> Self {
         Self {
             pointer: std::ptr::null_mut(),
@@ -160,6 +231,19 @@ impl<T, E> Pointer<T, E> {
     pub fn is_null(&self) -> bool {
         self.pointer.is_null()
     }
+
+    /// Gibt an, ob auf ein T (true) oder ein E (false) gezeigt wird.
+    #[inline]
+    fn is_first(&self) -> bool {
+        self.pointer as usize & 1 == 0
+    }
+
+    /// Entfernt das Markierungsbit und gibt den Zeiger auf das gespeicherte E zurück.
+    #[inline]
+    fn second(&self) -> *mut E {
+        debug_assert!(!self.is_first());
+        self.pointer.cast::<u8>().wrapping_sub(1).cast::<E>()
+    }
 }
 
 /// Dies ist ein Wrapper um die Mphf-Hashfunktion. Es wird nicht die interne Implementierung verwendet, da